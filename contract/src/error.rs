@@ -65,4 +65,154 @@ pub enum LumentixError {
 
     /// No platform fees available to withdraw
     NoPlatformFees = 20,
+
+    /// Ticket tier with the specified ID does not exist for the event
+    TierNotFound = 21,
+
+    /// Ticket tier IDs must be unique within an event
+    DuplicateTier = 22,
+
+    /// Purchase quantity must be between 1 and the maximum batch size
+    InvalidQuantity = 23,
+
+    /// Resale has not been enabled for this event
+    ResaleNotAllowed = 24,
+
+    /// Resale price exceeds the organizer's price cap
+    ResalePriceTooHigh = 25,
+
+    /// Ticket is not listed for resale
+    TicketNotListed = 26,
+
+    /// Ticket is already listed for resale
+    TicketAlreadyListed = 27,
+
+    /// Basis points value must be between 0 and 10000
+    InvalidBasisPoints = 28,
+
+    /// Refund schedule cutoffs must be strictly decreasing with non-increasing percentages
+    InvalidRefundSchedule = 29,
+
+    /// Platform balance cannot cover the fee being reversed
+    InsufficientPlatformBalance = 30,
+
+    /// Address is already on the waitlist for this event
+    AlreadyOnWaitlist = 31,
+
+    /// Address is not on the waitlist for this event
+    NotOnWaitlist = 32,
+
+    /// No active waitlist offer for this buyer, or the offer has expired
+    NoActiveOffer = 33,
+
+    /// Promo code is not registered for this event
+    PromoCodeNotFound = 34,
+
+    /// Promo code has expired or reached its redemption cap
+    PromoCodeUnavailable = 35,
+
+    /// Buyer is not on the presale allowlist (invalid Merkle proof)
+    NotAllowlisted = 36,
+
+    /// Buyer has reached the per-address presale limit
+    PresaleLimitExceeded = 37,
+
+    /// Auction prices must satisfy start > floor > 0 with a positive step and period
+    InvalidAuction = 38,
+
+    /// Auction has not ended yet (clearing price unknown)
+    AuctionNotEnded = 39,
+
+    /// Clearing price rebate has already been claimed for this ticket
+    RebateAlreadyClaimed = 40,
+
+    /// Pricing curve steps must be positive and thresholds strictly ascending
+    InvalidPricingCurve = 41,
+
+    /// Payment token is not accepted for this event
+    TokenNotAccepted = 42,
+
+    /// Payment token is listed more than once for an event
+    DuplicateToken = 43,
+
+    /// No price oracle has been configured by the admin
+    OracleNotConfigured = 44,
+
+    /// Oracle has no price, or its latest price is older than the staleness limit
+    StalePrice = 45,
+
+    /// Oracle price moved more than the deviation limit since the last accepted price
+    PriceDeviationTooHigh = 46,
+
+    /// Converted price exceeds the buyer's maximum payment
+    SlippageExceeded = 47,
+
+    /// Field cannot be changed in the event's current status
+    UpdateNotAllowed = 48,
+
+    /// Postponement opt-out window is closed
+    OptOutWindowClosed = 49,
+
+    /// No admin handover has been proposed
+    NoPendingAdmin = 50,
+
+    /// Admin threshold must be between 1 and the number of admins
+    InvalidThreshold = 51,
+
+    /// Admin has already approved this action
+    AlreadyApproved = 52,
+
+    /// Not enough admin approvals for this action yet
+    ThresholdNotMet = 53,
+
+    /// Operation is paused by the admin
+    ContractPaused = 54,
+
+    /// Stored data is already at the current schema version
+    AlreadyMigrated = 55,
+
+    /// TTL threshold must be below the extension target, which must not exceed the max TTL
+    InvalidTtlConfig = 56,
+
+    /// Revenue split shares must sum to 10000 bps across distinct payees, including the primary
+    InvalidRevenueSplit = 57,
+
+    /// Advance policy shares must stay below 10000 bps in total
+    InvalidAdvancePolicy = 58,
+
+    /// Amount exceeds the escrow advance unlocked by the event's milestones
+    AdvanceLimitExceeded = 59,
+
+    /// Disputes can only be opened between the event's end time and the end of the dispute window
+    DisputeWindowClosed = 60,
+
+    /// A dispute has already been opened for this event
+    DisputeAlreadyOpen = 61,
+
+    /// No dispute has been opened for this event (or it is already resolved)
+    DisputeNotFound = 62,
+
+    /// Escrow is frozen by an open dispute or an unexpired dispute window
+    EscrowFrozen = 63,
+
+    /// String field is longer than allowed
+    StringTooLong = 64,
+
+    /// The event's waitlist already holds the maximum number of addresses
+    WaitlistFull = 65,
+
+    /// Single-admin handover is not available in multi-admin mode (use SetAdmins)
+    HandoverNotAllowed = 66,
+
+    /// Stored records are still being migrated to the current schema version
+    MigrationPending = 67,
+
+    /// Postponed event cannot be re-published while its opt-out window is open
+    OptOutWindowOpen = 68,
+
+    /// No postponement has been recorded for this event
+    PostponementNotFound = 69,
+
+    /// Refunds under a dispute ruling exceed the escrow until advances are repaid
+    RefundUnderfunded = 70,
 }
@@ -1,22 +1,669 @@
-use soroban_sdk::{symbol_short, Address, Env, Symbol};
+use crate::types::{
+    AcceptedToken, DisputeResolution, EventRole, EventStatus, EventUpdate, PauseScope, RefundRule,
+    TicketTier,
+};
+use soroban_sdk::{contractevent, symbol_short, Address, Env, String, Symbol, Vec};
+
+/// Version published as the second topic of every versioned event below.
+/// Bump it (and the `"v1"` in each `topics` list) whenever a payload layout
+/// changes so indexers can decode both.
+pub const EVENTS_VERSION: Symbol = symbol_short!("v1");
 
 //a type for tranfer of event
-pub struct TransferEvent;
+#[contractevent(topics = ["transfer"], data_format = "vec")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransferEvent {
+    pub ticket_id: Symbol,
+    pub from: Address,
+    pub to: Address,
+}
 
 impl TransferEvent {
     pub fn emit(env: &Env, ticket_id: Symbol, from: Address, to: Address) {
-        env.events()
-            .publish((symbol_short!("transfer"),), (ticket_id, from, to));
+        TransferEvent {
+            ticket_id,
+            from,
+            to,
+        }
+        .publish(env);
     }
 }
 
 /// Event emitted when a ticket is checked in (validated)
-pub struct CheckInEvent;
+#[contractevent(topics = ["checkin"], data_format = "vec")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CheckInEvent {
+    pub ticket_id: Symbol,
+    pub validator: Address,
+    pub event_id: Symbol,
+}
 
 impl CheckInEvent {
     pub fn emit(env: &Env, ticket_id: Symbol, validator: Address, event_id: Symbol) {
-        env.events()
-            .publish((symbol_short!("checkin"),), (ticket_id, validator, event_id));
+        CheckInEvent {
+            ticket_id,
+            validator,
+            event_id,
+        }
+        .publish(env);
     }
 }
+
+// Versioned events: topics are (name, EVENTS_VERSION, event_id) and the payload
+// is a map of the remaining fields
+
+/// Event emitted when an organizer creates an event, carrying its sale terms
+#[contractevent(topics = ["ev_create", "v1"])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventCreatedEvent {
+    #[topic]
+    pub event_id: u64,
+    pub organizer: Address,
+    pub name: String,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub tiers: Vec<TicketTier>,
+    pub accepted_tokens: Vec<AcceptedToken>,
+    pub refund_schedule: Vec<RefundRule>,
+    pub reference_currency: Option<Symbol>,
+}
+
+/// Event emitted when an organizer updates an event's details, carrying the
+/// update as applied (unset fields were left unchanged)
+#[contractevent(topics = ["ev_update", "v1"])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventUpdatedEvent {
+    #[topic]
+    pub event_id: u64,
+    pub organizer: Address,
+    pub status: EventStatus,
+    pub update: EventUpdate,
+}
+
+/// Event emitted when an event changes status
+#[contractevent(topics = ["ev_status", "v1"])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatusChangedEvent {
+    #[topic]
+    pub event_id: u64,
+    pub old_status: EventStatus,
+    pub new_status: EventStatus,
+}
+
+/// Event emitted for every ticket sold, with its fee breakdown
+#[contractevent(topics = ["tkt_buy", "v1"])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TicketPurchasedEvent {
+    #[topic]
+    pub event_id: u64,
+    pub ticket_id: u64,
+    pub tier_id: u32,
+    pub buyer: Address,
+    pub payment_token: Address,
+    pub price_paid: i128,
+    pub platform_fee: i128,
+    pub organizer_amount: i128,
+}
+
+/// Event emitted when a ticket is checked in
+#[contractevent(topics = ["tkt_used", "v1"])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TicketUsedEvent {
+    #[topic]
+    pub event_id: u64,
+    pub ticket_id: u64,
+    pub validator: Address,
+}
+
+/// Event emitted when a ticket is refunded
+#[contractevent(topics = ["tkt_rfnd", "v1"])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TicketRefundedEvent {
+    #[topic]
+    pub event_id: u64,
+    pub ticket_id: u64,
+    pub owner: Address,
+    pub payment_token: Address,
+    pub amount: i128,
+    pub platform_fee_reversed: i128,
+}
+
+/// Event emitted when escrow is released to the organizer
+#[contractevent(topics = ["esc_rel", "v1"])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EscrowReleasedEvent {
+    #[topic]
+    pub event_id: u64,
+    pub organizer: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Event emitted for each payee share when escrow is released
+#[contractevent(topics = ["payout", "v1"])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PayoutSentEvent {
+    #[topic]
+    pub event_id: u64,
+    pub payee: Address,
+    pub token: Address,
+    pub share_bps: u32,
+    pub amount: i128,
+}
+
+/// Event emitted when an organizer draws an escrow advance
+#[contractevent(topics = ["esc_adv", "v1"])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AdvanceReleasedEvent {
+    #[topic]
+    pub event_id: u64,
+    pub organizer: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub total_advanced: i128,
+}
+
+/// Event emitted when a cancelled event's escrow cannot cover all refunds
+/// because advances were drawn
+#[contractevent(topics = ["esc_short", "v1"])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EscrowUnderfundedEvent {
+    #[topic]
+    pub event_id: u64,
+    pub token: Address,
+    pub shortfall: i128,
+}
+
+/// Event emitted when an attendee opens a dispute
+#[contractevent(topics = ["dsp_open", "v1"])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisputeOpenedEvent {
+    #[topic]
+    pub event_id: u64,
+    pub opened_by: Address,
+    pub ticket_id: u64,
+    pub reason: String,
+}
+
+/// Event emitted when the admin resolves a dispute
+#[contractevent(topics = ["dsp_rslv", "v1"])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisputeResolvedEvent {
+    #[topic]
+    pub event_id: u64,
+    pub resolution: DisputeResolution,
+    pub resolved_by: Address,
+}
+
+/// Event emitted when an organizer grants a staff role for an event
+#[contractevent(topics = ["role_add", "v1"])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoleGrantedEvent {
+    #[topic]
+    pub event_id: u64,
+    pub account: Address,
+    pub role: EventRole,
+}
+
+/// Event emitted when an organizer revokes a staff role for an event
+#[contractevent(topics = ["role_rm", "v1"])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoleRevokedEvent {
+    #[topic]
+    pub event_id: u64,
+    pub account: Address,
+    pub role: EventRole,
+}
+
+/// Event emitted when a buyer joins an event's waitlist
+#[contractevent(topics = ["wl_join", "v1"])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WaitlistJoinedEvent {
+    #[topic]
+    pub event_id: u64,
+    pub buyer: Address,
+    pub position: u32,
+}
+
+/// Event emitted when a buyer leaves an event's waitlist
+#[contractevent(topics = ["wl_leave", "v1"])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WaitlistLeftEvent {
+    #[topic]
+    pub event_id: u64,
+    pub buyer: Address,
+}
+
+/// Event emitted when the next waitlisted buyer receives an exclusive offer
+#[contractevent(topics = ["wl_offer", "v1"])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WaitlistOfferEvent {
+    #[topic]
+    pub event_id: u64,
+    pub buyer: Address,
+    pub price: i128,
+    pub expires_at: u64,
+}
+
+/// Event emitted when a waitlist offer expires and passes to the next buyer
+#[contractevent(topics = ["wl_expire", "v1"])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WaitlistOfferExpiredEvent {
+    #[topic]
+    pub event_id: u64,
+    pub buyer: Address,
+}
+
+// Platform-wide events have no event ID topic: (name, EVENTS_VERSION), plus the
+// scope for pause changes
+
+/// Event emitted when the admin pauses or unpauses an operation scope
+#[contractevent(topics = ["paused", "v1"], data_format = "single-value")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PauseChangedEvent {
+    #[topic]
+    pub scope: PauseScope,
+    pub paused: bool,
+}
+
+/// Event emitted when the admin changes the platform fee
+#[contractevent(topics = ["fee_set", "v1"])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlatformFeeChangedEvent {
+    pub old_fee_bps: u32,
+    pub new_fee_bps: u32,
+}
+
+/// Event emitted when the admin withdraws platform fees
+#[contractevent(topics = ["fee_wdraw", "v1"])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlatformFeesWithdrawnEvent {
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{
+        contract,
+        testutils::{Address as _, Events},
+        vec, Event, IntoVal, Map, Val,
+    };
+
+    #[contract]
+    struct EventsHost;
+
+    #[test]
+    fn test_event_lifecycle_payloads() {
+        let env = Env::default();
+        let contract_id = env.register(EventsHost, ());
+        let organizer = Address::generate(&env);
+
+        let tiers = vec![
+            &env,
+            TicketTier {
+                id: 1,
+                name: String::from_str(&env, "General Admission"),
+                price: 100,
+                max_tickets: 50,
+                tickets_sold: 0,
+            },
+        ];
+        let accepted_tokens = vec![
+            &env,
+            AcceptedToken {
+                token: Address::generate(&env),
+                tier_id: 1,
+                price: 250,
+            },
+        ];
+        let refund_schedule = vec![
+            &env,
+            RefundRule {
+                cutoff_secs: 86400,
+                refund_bps: 10000,
+            },
+        ];
+        let created = EventCreatedEvent {
+            event_id: 7,
+            organizer: organizer.clone(),
+            name: String::from_str(&env, "Test Event"),
+            start_time: 1000,
+            end_time: 2000,
+            tiers: tiers.clone(),
+            accepted_tokens: accepted_tokens.clone(),
+            refund_schedule: refund_schedule.clone(),
+            reference_currency: Some(symbol_short!("USD")),
+        };
+        let status = StatusChangedEvent {
+            event_id: 7,
+            old_status: EventStatus::Draft,
+            new_status: EventStatus::Published,
+        };
+
+        env.as_contract(&contract_id, || {
+            created.publish(&env);
+            status.publish(&env);
+        });
+
+        // The event ID is a topic, so only the remaining fields are in the payload
+        let mut created_data: Map<Symbol, Val> = Map::new(&env);
+        created_data.set(symbol_short!("organizer"), organizer.into_val(&env));
+        created_data.set(
+            symbol_short!("name"),
+            String::from_str(&env, "Test Event").into_val(&env),
+        );
+        created_data.set(Symbol::new(&env, "start_time"), 1000u64.into_val(&env));
+        created_data.set(symbol_short!("end_time"), 2000u64.into_val(&env));
+        created_data.set(symbol_short!("tiers"), tiers.into_val(&env));
+        created_data.set(
+            Symbol::new(&env, "accepted_tokens"),
+            accepted_tokens.into_val(&env),
+        );
+        created_data.set(
+            Symbol::new(&env, "refund_schedule"),
+            refund_schedule.into_val(&env),
+        );
+        created_data.set(
+            Symbol::new(&env, "reference_currency"),
+            Some(symbol_short!("USD")).into_val(&env),
+        );
+
+        let mut status_data: Map<Symbol, Val> = Map::new(&env);
+        status_data.set(
+            Symbol::new(&env, "old_status"),
+            EventStatus::Draft.into_val(&env),
+        );
+        status_data.set(
+            Symbol::new(&env, "new_status"),
+            EventStatus::Published.into_val(&env),
+        );
+
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (symbol_short!("ev_create"), EVENTS_VERSION, 7u64).into_val(&env),
+                    created_data.into_val(&env),
+                ),
+                (
+                    contract_id.clone(),
+                    (symbol_short!("ev_status"), EVENTS_VERSION, 7u64).into_val(&env),
+                    status_data.into_val(&env),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_event_updated_payload_carries_applied_update() {
+        let env = Env::default();
+        let contract_id = env.register(EventsHost, ());
+        let organizer = Address::generate(&env);
+
+        // VIP (tier 2) repriced to 300 with 20 seats
+        let update = EventUpdate {
+            name: None,
+            description: None,
+            location: None,
+            start_time: None,
+            end_time: None,
+            ticket_price: Some(300),
+            max_tickets: Some(20),
+            tier_id: Some(2),
+        };
+        let updated = EventUpdatedEvent {
+            event_id: 7,
+            organizer: organizer.clone(),
+            status: EventStatus::Draft,
+            update: update.clone(),
+        };
+        env.as_contract(&contract_id, || updated.publish(&env));
+
+        let mut data: Map<Symbol, Val> = Map::new(&env);
+        data.set(symbol_short!("organizer"), organizer.into_val(&env));
+        data.set(symbol_short!("status"), EventStatus::Draft.into_val(&env));
+        data.set(symbol_short!("update"), update.into_val(&env));
+
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (symbol_short!("ev_update"), EVENTS_VERSION, 7u64).into_val(&env),
+                    data.into_val(&env),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ticket_payloads() {
+        let env = Env::default();
+        let contract_id = env.register(EventsHost, ());
+        let buyer = Address::generate(&env);
+        let token = Address::generate(&env);
+
+        let purchased = TicketPurchasedEvent {
+            ticket_id: 3,
+            event_id: 7,
+            tier_id: 1,
+            buyer: buyer.clone(),
+            payment_token: token.clone(),
+            price_paid: 100,
+            platform_fee: 5,
+            organizer_amount: 95,
+        };
+        let used = TicketUsedEvent {
+            ticket_id: 3,
+            event_id: 7,
+            validator: Address::generate(&env),
+        };
+        let refunded = TicketRefundedEvent {
+            ticket_id: 3,
+            event_id: 7,
+            owner: buyer,
+            payment_token: token,
+            amount: 100,
+            platform_fee_reversed: 5,
+        };
+
+        env.as_contract(&contract_id, || {
+            purchased.publish(&env);
+            used.publish(&env);
+            refunded.publish(&env);
+        });
+
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (symbol_short!("tkt_buy"), EVENTS_VERSION, 7u64).into_val(&env),
+                    purchased.data(&env),
+                ),
+                (
+                    contract_id.clone(),
+                    (symbol_short!("tkt_used"), EVENTS_VERSION, 7u64).into_val(&env),
+                    used.data(&env),
+                ),
+                (
+                    contract_id.clone(),
+                    (symbol_short!("tkt_rfnd"), EVENTS_VERSION, 7u64).into_val(&env),
+                    refunded.data(&env),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_payout_payload() {
+        let env = Env::default();
+        let contract_id = env.register(EventsHost, ());
+
+        let payout = PayoutSentEvent {
+            event_id: 7,
+            payee: Address::generate(&env),
+            token: Address::generate(&env),
+            share_bps: 3000,
+            amount: 285,
+        };
+        env.as_contract(&contract_id, || payout.publish(&env));
+
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (symbol_short!("payout"), EVENTS_VERSION, 7u64).into_val(&env),
+                    payout.data(&env),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_settlement_and_platform_payloads() {
+        let env = Env::default();
+        let contract_id = env.register(EventsHost, ());
+        let token = Address::generate(&env);
+
+        let released = EscrowReleasedEvent {
+            event_id: 7,
+            organizer: Address::generate(&env),
+            token: token.clone(),
+            amount: 950,
+        };
+        let fee_changed = PlatformFeeChangedEvent {
+            old_fee_bps: 250,
+            new_fee_bps: 500,
+        };
+        let withdrawn = PlatformFeesWithdrawnEvent {
+            recipient: Address::generate(&env),
+            token,
+            amount: 50,
+        };
+
+        env.as_contract(&contract_id, || {
+            released.publish(&env);
+            fee_changed.publish(&env);
+            withdrawn.publish(&env);
+        });
+
+        let mut fee_data: Map<Symbol, Val> = Map::new(&env);
+        fee_data.set(Symbol::new(&env, "old_fee_bps"), 250u32.into_val(&env));
+        fee_data.set(Symbol::new(&env, "new_fee_bps"), 500u32.into_val(&env));
+
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (symbol_short!("esc_rel"), EVENTS_VERSION, 7u64).into_val(&env),
+                    released.data(&env),
+                ),
+                (
+                    contract_id.clone(),
+                    (symbol_short!("fee_set"), EVENTS_VERSION).into_val(&env),
+                    fee_data.into_val(&env),
+                ),
+                (
+                    contract_id.clone(),
+                    (symbol_short!("fee_wdraw"), EVENTS_VERSION).into_val(&env),
+                    withdrawn.data(&env),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_staff_waitlist_and_pause_payloads() {
+        let env = Env::default();
+        let contract_id = env.register(EventsHost, ());
+        let buyer = Address::generate(&env);
+
+        let granted = RoleGrantedEvent {
+            event_id: 7,
+            account: Address::generate(&env),
+            role: EventRole::GateStaff,
+        };
+        let joined = WaitlistJoinedEvent {
+            event_id: 7,
+            buyer: buyer.clone(),
+            position: 2,
+        };
+        let paused = PauseChangedEvent {
+            scope: PauseScope::Purchases,
+            paused: true,
+        };
+
+        env.as_contract(&contract_id, || {
+            granted.publish(&env);
+            joined.publish(&env);
+            paused.publish(&env);
+        });
+
+        let mut joined_data: Map<Symbol, Val> = Map::new(&env);
+        joined_data.set(symbol_short!("buyer"), buyer.into_val(&env));
+        joined_data.set(symbol_short!("position"), 2u32.into_val(&env));
+
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (symbol_short!("role_add"), EVENTS_VERSION, 7u64).into_val(&env),
+                    granted.data(&env),
+                ),
+                (
+                    contract_id.clone(),
+                    (symbol_short!("wl_join"), EVENTS_VERSION, 7u64).into_val(&env),
+                    joined_data.into_val(&env),
+                ),
+                (
+                    contract_id.clone(),
+                    (
+                        symbol_short!("paused"),
+                        EVENTS_VERSION,
+                        PauseScope::Purchases
+                    )
+                        .into_val(&env),
+                    true.into_val(&env),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_transfer_keeps_unversioned_tuple_payload() {
+        let env = Env::default();
+        let contract_id = env.register(EventsHost, ());
+        let from = Address::generate(&env);
+        let to = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            TransferEvent::emit(&env, symbol_short!("t1"), from.clone(), to.clone())
+        });
+
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (symbol_short!("transfer"),).into_val(&env),
+                    (symbol_short!("t1"), from, to).into_val(&env),
+                ),
+            ]
+        );
+    }
 }
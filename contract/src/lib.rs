@@ -169,8 +169,10 @@ mod tests {
 }
 
 mod contract;
+mod error;
 mod events;
 mod models;
+mod types;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,196 @@
+use crate::error::LumentixError;
+use crate::types::{OracleConfig, PriceData};
+use soroban_sdk::{contractclient, Address, Env, Symbol};
+
+/// Interface the admin-configured price oracle contract must implement
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracle {
+    /// Latest price of `asset` in `currency`, or None if the oracle has no quote
+    fn lastprice(env: Env, currency: Symbol, asset: Address) -> Option<PriceData>;
+
+    /// Number of decimals the oracle scales prices by
+    fn decimals(env: Env) -> u32;
+}
+
+/// Fetch the oracle price for a currency/token pair, rejecting stale quotes
+pub fn get_oracle_price(
+    env: &Env,
+    config: &OracleConfig,
+    currency: &Symbol,
+    token: &Address,
+) -> Result<(PriceData, u32), LumentixError> {
+    let client = PriceOracleClient::new(env, &config.oracle);
+    let data = client
+        .lastprice(currency, token)
+        .ok_or(LumentixError::StalePrice)?;
+
+    let now = env.ledger().timestamp();
+    if data.price <= 0 || now.saturating_sub(data.timestamp) > config.max_staleness {
+        return Err(LumentixError::StalePrice);
+    }
+
+    Ok((data, client.decimals()))
+}
+
+/// Validate that a new price is within `max_deviation_bps` of the last accepted
+/// price. The last price is only a reference for `deviation_window` seconds
+/// after it was accepted, so a lasting market move is accepted once it expires.
+pub fn validate_price_deviation(
+    config: &OracleConfig,
+    price: i128,
+    last_price: Option<PriceData>,
+    now: u64,
+) -> Result<(), LumentixError> {
+    let last = match last_price {
+        Some(last) if now.saturating_sub(last.timestamp) <= config.deviation_window => last.price,
+        _ => return Ok(()),
+    };
+
+    let deviation = (price - last)
+        .abs()
+        .checked_mul(10000)
+        .ok_or(LumentixError::PriceDeviationTooHigh)?;
+    let allowed = last
+        .checked_mul(config.max_deviation_bps as i128)
+        .ok_or(LumentixError::InvalidAmount)?;
+    if deviation > allowed {
+        return Err(LumentixError::PriceDeviationTooHigh);
+    }
+    Ok(())
+}
+
+/// Convert a reference-currency amount to token units, rounding up so the
+/// organizer never receives less than the pegged price
+pub fn convert_to_token_amount(
+    reference_amount: i128,
+    price: i128,
+    decimals: u32,
+) -> Result<i128, LumentixError> {
+    let scale = 10i128
+        .checked_pow(decimals)
+        .ok_or(LumentixError::InvalidAmount)?;
+    let numerator = reference_amount
+        .checked_mul(price)
+        .ok_or(LumentixError::InvalidAmount)?;
+    let rounded = numerator
+        .checked_add(scale - 1)
+        .ok_or(LumentixError::InvalidAmount)?;
+    Ok(rounded / scale)
+}
+
+/// Validate the converted price against the buyer's maximum payment
+pub fn validate_max_payment(amount: i128, max_payment: i128) -> Result<(), LumentixError> {
+    if amount > max_payment {
+        return Err(LumentixError::SlippageExceeded);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{
+        contract, contractimpl, symbol_short,
+        testutils::{Address as _, Ledger},
+    };
+
+    #[contract]
+    struct MockOracle;
+
+    #[contractimpl]
+    impl MockOracle {
+        pub fn set_price(env: Env, price: i128, timestamp: u64) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("price"), &PriceData { price, timestamp });
+        }
+
+        pub fn lastprice(env: Env, _currency: Symbol, _asset: Address) -> Option<PriceData> {
+            env.storage().instance().get(&symbol_short!("price"))
+        }
+
+        pub fn decimals(_env: Env) -> u32 {
+            7
+        }
+    }
+
+    fn setup(env: &Env) -> (OracleConfig, MockOracleClient<'_>) {
+        let oracle = env.register(MockOracle, ());
+        let config = OracleConfig {
+            oracle: oracle.clone(),
+            max_staleness: 300,
+            max_deviation_bps: 1000,
+            deviation_window: 3600,
+        };
+        (config, MockOracleClient::new(env, &oracle))
+    }
+
+    #[test]
+    fn test_get_oracle_price() {
+        let env = Env::default();
+        env.ledger().set_timestamp(10_000);
+        let (config, oracle) = setup(&env);
+        let usd = symbol_short!("USD");
+        let token = Address::generate(&env);
+
+        assert_eq!(
+            get_oracle_price(&env, &config, &usd, &token),
+            Err(LumentixError::StalePrice)
+        );
+
+        // 1 USD = 8.5 token units at 7 decimals
+        oracle.set_price(&85_000_000, &9_800);
+        let (data, decimals) = get_oracle_price(&env, &config, &usd, &token).unwrap();
+        assert_eq!(data.price, 85_000_000);
+        assert_eq!(decimals, 7);
+        assert_eq!(convert_to_token_amount(20, data.price, decimals), Ok(170));
+
+        oracle.set_price(&85_000_000, &9_699);
+        assert_eq!(
+            get_oracle_price(&env, &config, &usd, &token),
+            Err(LumentixError::StalePrice)
+        );
+    }
+
+    #[test]
+    fn test_validate_price_deviation() {
+        let env = Env::default();
+        let (config, _) = setup(&env);
+        let last = Some(PriceData {
+            price: 100,
+            timestamp: 10_000,
+        });
+
+        assert!(validate_price_deviation(&config, 110, None, 10_000).is_ok());
+        assert!(validate_price_deviation(&config, 110, last.clone(), 10_000).is_ok());
+        assert!(validate_price_deviation(&config, 90, last.clone(), 10_000).is_ok());
+        assert_eq!(
+            validate_price_deviation(&config, 111, last.clone(), 13_600),
+            Err(LumentixError::PriceDeviationTooHigh)
+        );
+
+        // Once the reference is older than the window, a lasting move is accepted
+        assert!(validate_price_deviation(&config, 150, last, 13_601).is_ok());
+    }
+
+    #[test]
+    fn test_convert_and_max_payment() {
+        // Rounds up to the next token unit
+        assert_eq!(convert_to_token_amount(1, 15, 1), Ok(2));
+        assert_eq!(
+            convert_to_token_amount(i128::MAX, 2, 0),
+            Err(LumentixError::InvalidAmount)
+        );
+        // Rounding up cannot overflow either
+        assert_eq!(
+            convert_to_token_amount(i128::MAX, 1, 1),
+            Err(LumentixError::InvalidAmount)
+        );
+
+        assert!(validate_max_payment(170, 170).is_ok());
+        assert_eq!(
+            validate_max_payment(171, 170),
+            Err(LumentixError::SlippageExceeded)
+        );
+    }
+}
@@ -1,22 +1,86 @@
 use crate::error::LumentixError;
-use crate::types::{Event, Ticket};
-use soroban_sdk::{Address, Env};
+use crate::types::{
+    AdminAction, AdvancePolicy, Dispute, DutchAuction, Event, EventRole, EventV1, OracleConfig,
+    PauseScope, Postponement, PresaleConfig, PriceData, PricingCurve, PromoCode, ResaleListing,
+    ResalePolicy, RevenueSplit, Ticket, TicketV1, TtlConfig, WaitlistOffer,
+};
+use crate::validation::{calculate_clearing_rebate, validate_approvals, validate_bps};
+use soroban_sdk::{Address, BytesN, Env, IntoVal, Symbol, TryFromVal, Val, Vec};
+
+/// Approximate number of ledgers closed per day (5 second close time)
+pub const DAY_IN_LEDGERS: u32 = 17280;
+
+/// Default TTL policy: entries with less than a week left are extended to 30 days
+pub const DEFAULT_TTL_THRESHOLD: u32 = 7 * DAY_IN_LEDGERS;
+pub const DEFAULT_TTL_EXTEND_TO: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Default post-event dispute window (3 days)
+pub const DEFAULT_DISPUTE_WINDOW: u64 = 3 * 86400;
+
+/// Maximum number of ids returned by one page of an index query
+/// Each id is its own ledger entry, so a page stays within the
+/// per-invocation limit on read entries
+pub const MAX_PAGE_SIZE: u32 = 50;
 
 // Storage keys
 const INITIALIZED: &str = "INIT";
+const SCHEMA_VERSION: &str = "SCHEMA_VER";
+const MIGRATION_CURSOR: &str = "MIGRATION_CURSOR";
+const TTL_CONFIG: &str = "TTL_CONFIG";
 const ADMIN: &str = "ADMIN";
+const PENDING_ADMIN: &str = "PENDING_ADMIN";
+const ADMINS: &str = "ADMINS";
+const ADMIN_THRESHOLD: &str = "ADMIN_THRESHOLD";
+const APPROVALS_PREFIX: &str = "APPROVALS_";
 const TOKEN: &str = "TOKEN";
+const PAUSED_PREFIX: &str = "PAUSED_";
+const ORACLE_CONFIG: &str = "ORACLE";
+const LAST_PRICE_PREFIX: &str = "LAST_PRICE_";
 const EVENT_ID_COUNTER: &str = "EVENT_CTR";
 const TICKET_ID_COUNTER: &str = "TICKET_CTR";
 const EVENT_PREFIX: &str = "EVENT_";
+const ORGANIZER_EVENTS_PREFIX: &str = "ORG_EVENTS_";
 const TICKET_PREFIX: &str = "TICKET_";
+const OWNER_TICKETS_PREFIX: &str = "OWNER_TICKETS_";
+const EVENT_TICKETS_PREFIX: &str = "EVENT_TICKETS_";
+const INDEX_ITEM: &str = "ITEM";
+const INDEX_SLOT: &str = "SLOT";
 const ESCROW_PREFIX: &str = "ESCROW_";
+const ADVANCE_POLICY_PREFIX: &str = "ADV_POLICY_";
+const ADVANCED_PREFIX: &str = "ADVANCED_";
+const CHECKIN_COUNT_PREFIX: &str = "CHECKINS_";
+const TIER_ESCROW_PREFIX: &str = "TIER_ESCROW_";
+const REVENUE_SPLIT_PREFIX: &str = "REV_SPLIT_";
+const RESALE_POLICY_PREFIX: &str = "RESALE_POL_";
+const RESALE_LISTING_PREFIX: &str = "RESALE_";
+const WAITLIST_PREFIX: &str = "WAITLIST_";
+const WAITLIST_OFFER_PREFIX: &str = "WL_OFFER_";
+const PROMO_PREFIX: &str = "PROMO_";
+const PRESALE_CONFIG_PREFIX: &str = "PRESALE_CFG_";
+const PRESALE_PREFIX: &str = "PRESALE_";
+const PRICING_CURVE_PREFIX: &str = "CURVE_";
+const AUCTION_PREFIX: &str = "AUCTION_";
+const CLEARING_PRICE_PREFIX: &str = "CLEAR_PRICE_";
+const REBATE_PREFIX: &str = "REBATE_";
+const POSTPONEMENT_PREFIX: &str = "POSTPONE_";
+const ROLE_PREFIX: &str = "ROLE_";
+const DISPUTE_WINDOW: &str = "DISPUTE_WINDOW";
+const DISPUTE_PREFIX: &str = "DISPUTE_";
 const PLATFORM_FEE_BPS: &str = "PLATFORM_FEE_BPS";
+const ORGANIZER_FEE_PREFIX: &str = "ORG_FEE_";
+const EVENT_FEE_PREFIX: &str = "EVENT_FEE_";
 const PLATFORM_BALANCE: &str = "PLATFORM_BAL";
+const PENDING_FEES: &str = "PENDING_FEES";
+const EVENT_FEES_PREFIX: &str = "EVENT_FEES_";
 
 /// Check if contract is initialized
+/// Every entry point checks this first, so it also keeps the instance entry live
 pub fn is_initialized(env: &Env) -> bool {
-    env.storage().instance().has(&INITIALIZED)
+    let initialized = env.storage().instance().has(&INITIALIZED);
+    if initialized {
+        extend_instance(env);
+    }
+    initialized
 }
 
 /// Mark contract as initialized
@@ -24,6 +88,99 @@ pub fn set_initialized(env: &Env) {
     env.storage().instance().set(&INITIALIZED, &true);
 }
 
+/// Set the storage TTL policy
+pub fn set_ttl_config(env: &Env, config: &TtlConfig) {
+    env.storage().instance().set(&TTL_CONFIG, config);
+}
+
+/// Get the storage TTL policy (DEFAULT_TTL_THRESHOLD / DEFAULT_TTL_EXTEND_TO until set)
+pub fn get_ttl_config(env: &Env) -> TtlConfig {
+    env.storage()
+        .instance()
+        .get(&TTL_CONFIG)
+        .unwrap_or(TtlConfig {
+            threshold: DEFAULT_TTL_THRESHOLD,
+            extend_to: DEFAULT_TTL_EXTEND_TO,
+        })
+}
+
+/// Extend the instance entry (admin, config, counters, platform balances)
+pub fn extend_instance(env: &Env) {
+    let config = get_ttl_config(env);
+    env.storage()
+        .instance()
+        .extend_ttl(config.threshold, config.extend_to);
+}
+
+/// Extend a persistent entry if it exists
+fn extend_persistent<K>(env: &Env, key: &K)
+where
+    K: IntoVal<Env, Val>,
+{
+    if env.storage().persistent().has(key) {
+        let config = get_ttl_config(env);
+        env.storage()
+            .persistent()
+            .extend_ttl(key, config.threshold, config.extend_to);
+    }
+}
+
+/// Read a persistent entry, extending it when it exists
+/// Every persistent key goes through this and `set_persistent`, so entries
+/// stay live for as long as they are in use
+fn get_persistent<K, V>(env: &Env, key: &K) -> Option<V>
+where
+    K: IntoVal<Env, Val>,
+    V: TryFromVal<Env, Val>,
+{
+    let value = env.storage().persistent().get(key);
+    if value.is_some() {
+        let config = get_ttl_config(env);
+        env.storage()
+            .persistent()
+            .extend_ttl(key, config.threshold, config.extend_to);
+    }
+    value
+}
+
+/// Write a persistent entry and extend it
+fn set_persistent<K, V>(env: &Env, key: &K, value: &V)
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val>,
+{
+    env.storage().persistent().set(key, value);
+    let config = get_ttl_config(env);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, config.threshold, config.extend_to);
+}
+
+/// Set the schema version of stored Event/Ticket records
+pub fn set_schema_version(env: &Env, version: u32) {
+    env.storage().instance().set(&SCHEMA_VERSION, &version);
+}
+
+/// Get the schema version of stored records (1 for contracts deployed before versioning)
+pub fn get_schema_version(env: &Env) -> u32 {
+    env.storage().instance().get(&SCHEMA_VERSION).unwrap_or(1)
+}
+
+/// Record the next position an interrupted migration resumes from
+pub fn set_migration_cursor(env: &Env, position: u64) {
+    env.storage().instance().set(&MIGRATION_CURSOR, &position);
+}
+
+/// Get the next position to migrate (1 when no migration is in progress)
+pub fn get_migration_cursor(env: &Env) -> u64 {
+    env.storage().instance().get(&MIGRATION_CURSOR).unwrap_or(1)
+}
+
+/// Clear migration progress (after the migration completes)
+pub fn clear_migration_cursor(env: &Env) {
+    env.storage().instance().remove(&MIGRATION_CURSOR);
+}
+
 /// Set admin address
 pub fn set_admin(env: &Env, admin: &Address) {
     env.storage().instance().set(&ADMIN, admin);
@@ -34,16 +191,172 @@ pub fn get_admin(env: &Env) -> Address {
     env.storage().instance().get(&ADMIN).unwrap()
 }
 
-/// Set token address
+/// Set proposed admin for a two-step handover
+/// Only the single admin hands over this way; in multi-admin mode the admin
+/// set is changed through an approved AdminAction::SetAdmins instead
+pub fn set_pending_admin(env: &Env, admin: &Address) -> Result<(), LumentixError> {
+    if is_multi_admin(env) {
+        return Err(LumentixError::HandoverNotAllowed);
+    }
+    env.storage().instance().set(&PENDING_ADMIN, admin);
+    Ok(())
+}
+
+/// Get proposed admin
+pub fn get_pending_admin(env: &Env) -> Result<Address, LumentixError> {
+    env.storage()
+        .instance()
+        .get(&PENDING_ADMIN)
+        .ok_or(LumentixError::NoPendingAdmin)
+}
+
+/// Clear proposed admin (after acceptance or cancellation)
+pub fn clear_pending_admin(env: &Env) {
+    env.storage().instance().remove(&PENDING_ADMIN);
+}
+
+/// Set the admin set and approval threshold for multi-admin mode
+/// Any pending single-admin handover is dropped
+pub fn set_admins(env: &Env, admins: &Vec<Address>, threshold: u32) {
+    env.storage().instance().set(&ADMINS, admins);
+    env.storage().instance().set(&ADMIN_THRESHOLD, &threshold);
+    clear_pending_admin(env);
+}
+
+/// Check if multi-admin mode is enabled
+pub fn is_multi_admin(env: &Env) -> bool {
+    env.storage().instance().has(&ADMINS)
+}
+
+/// Get the admin set (just the single admin unless multi-admin mode is enabled)
+pub fn get_admins(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&ADMINS)
+        .unwrap_or_else(|| Vec::from_array(env, [get_admin(env)]))
+}
+
+/// Get the number of admin approvals required for sensitive operations
+pub fn get_admin_threshold(env: &Env) -> u32 {
+    env.storage().instance().get(&ADMIN_THRESHOLD).unwrap_or(1)
+}
+
+/// Check if an address is an admin
+pub fn is_admin(env: &Env, account: &Address) -> bool {
+    get_admins(env).contains(account)
+}
+
+/// Get the current admins that have approved an action
+/// Approvals from addresses no longer in the admin set do not count
+pub fn get_approvals(env: &Env, action: &AdminAction) -> Vec<Address> {
+    let key = (APPROVALS_PREFIX, action.clone());
+    let stored: Vec<Address> = get_persistent(env, &key).unwrap_or_else(|| Vec::new(env));
+
+    let admins = get_admins(env);
+    let mut approvals = Vec::new(env);
+    for approver in stored.iter() {
+        if admins.contains(&approver) {
+            approvals.push_back(approver);
+        }
+    }
+    approvals
+}
+
+/// Record an admin's approval of an action, returning the approval count
+pub fn add_approval(
+    env: &Env,
+    action: &AdminAction,
+    admin: &Address,
+) -> Result<u32, LumentixError> {
+    if !is_admin(env, admin) {
+        return Err(LumentixError::Unauthorized);
+    }
+
+    let mut approvals = get_approvals(env, action);
+    if approvals.contains(admin) {
+        return Err(LumentixError::AlreadyApproved);
+    }
+
+    approvals.push_back(admin.clone());
+    let key = (APPROVALS_PREFIX, action.clone());
+    set_persistent(env, &key, &approvals);
+    Ok(approvals.len())
+}
+
+/// Clear approvals for an action (after execution)
+pub fn clear_approvals(env: &Env, action: &AdminAction) {
+    let key = (APPROVALS_PREFIX, action.clone());
+    env.storage().persistent().remove(&key);
+}
+
+/// In multi-admin mode, fail with ThresholdNotMet until enough current admins
+/// have approved the action
+pub fn require_approved(env: &Env, action: &AdminAction) -> Result<(), LumentixError> {
+    if is_multi_admin(env) {
+        validate_approvals(get_approvals(env, action).len(), get_admin_threshold(env))?;
+    }
+    Ok(())
+}
+
+/// Pause or unpause an operation scope
+pub fn set_paused(env: &Env, scope: PauseScope, paused: bool) {
+    env.storage()
+        .instance()
+        .set(&(PAUSED_PREFIX, scope), &paused);
+}
+
+/// Check if an operation scope is paused
+pub fn is_paused(env: &Env, scope: PauseScope) -> bool {
+    env.storage()
+        .instance()
+        .get(&(PAUSED_PREFIX, scope))
+        .unwrap_or(false)
+}
+
+/// Fail with ContractPaused if an operation scope is paused
+pub fn require_not_paused(env: &Env, scope: PauseScope) -> Result<(), LumentixError> {
+    if is_paused(env, scope) {
+        return Err(LumentixError::ContractPaused);
+    }
+    Ok(())
+}
+
+/// Set default payment token address (used by events without an accepted-token list)
 pub fn set_token(env: &Env, token: &Address) {
     env.storage().instance().set(&TOKEN, token);
 }
 
-/// Get token address
+/// Get default payment token address
 pub fn get_token(env: &Env) -> Address {
     env.storage().instance().get(&TOKEN).unwrap()
 }
 
+/// Set price oracle configuration
+pub fn set_oracle_config(env: &Env, config: &OracleConfig) {
+    env.storage().instance().set(&ORACLE_CONFIG, config);
+}
+
+/// Get price oracle configuration
+pub fn get_oracle_config(env: &Env) -> Result<OracleConfig, LumentixError> {
+    env.storage()
+        .instance()
+        .get(&ORACLE_CONFIG)
+        .ok_or(LumentixError::OracleNotConfigured)
+}
+
+/// Get the last accepted oracle price for a reference currency and token, with
+/// the ledger time it was accepted at
+pub fn get_last_price(env: &Env, currency: &Symbol, token: &Address) -> Option<PriceData> {
+    let key = (LAST_PRICE_PREFIX, currency.clone(), token.clone());
+    get_persistent(env, &key)
+}
+
+/// Record the last accepted oracle price for a reference currency and token
+pub fn set_last_price(env: &Env, currency: &Symbol, token: &Address, price: &PriceData) {
+    let key = (LAST_PRICE_PREFIX, currency.clone(), token.clone());
+    set_persistent(env, &key, price);
+}
+
 /// Get next event ID
 pub fn get_next_event_id(env: &Env) -> u64 {
     env.storage().instance().get(&EVENT_ID_COUNTER).unwrap_or(1)
@@ -69,66 +382,772 @@ pub fn increment_ticket_id(env: &Env) {
     env.storage().instance().set(&TICKET_ID_COUNTER, &next_id);
 }
 
+/// Reserve a contiguous block of ticket IDs for a batch purchase
+/// Returns the first reserved ID; the block is `[first, first + count)`
+pub fn reserve_ticket_ids(env: &Env, count: u32) -> u64 {
+    let first_id = get_next_ticket_id(env);
+    env.storage()
+        .instance()
+        .set(&TICKET_ID_COUNTER, &(first_id + count as u64));
+    first_id
+}
+
 /// Set event data
 pub fn set_event(env: &Env, event_id: u64, event: &Event) {
     let key = (EVENT_PREFIX, event_id);
-    env.storage().persistent().set(&key, event);
+    let is_new = !env.storage().persistent().has(&key);
+    set_persistent(env, &key, event);
+    extend_instance(env);
+
+    if is_new {
+        index_insert(
+            env,
+            &(ORGANIZER_EVENTS_PREFIX, event.organizer.clone()),
+            event_id,
+        );
+    }
 }
 
 /// Get event data
 pub fn get_event(env: &Env, event_id: u64) -> Result<Event, LumentixError> {
     let key = (EVENT_PREFIX, event_id);
-    env.storage()
-        .persistent()
-        .get(&key)
-        .ok_or(LumentixError::EventNotFound)
+    let event = get_persistent(env, &key).ok_or(LumentixError::EventNotFound)?;
+    extend_instance(env);
+    Ok(event)
+}
+
+/// Remove and return event data stored in the schema version 1 layout
+pub fn take_legacy_event(env: &Env, event_id: u64) -> Option<EventV1> {
+    let key = (EVENT_PREFIX, event_id);
+    let legacy = env.storage().persistent().get(&key);
+    env.storage().persistent().remove(&key);
+    legacy
 }
 
 /// Set ticket data
 pub fn set_ticket(env: &Env, ticket_id: u64, ticket: &Ticket) {
     let key = (TICKET_PREFIX, ticket_id);
-    env.storage().persistent().set(&key, ticket);
+    let previous: Option<Ticket> = get_persistent(env, &key);
+    set_persistent(env, &key, ticket);
+    extend_instance(env);
+
+    update_ticket_indexes(env, ticket_id, previous.as_ref(), ticket);
+}
+
+/// Keep the event -> tickets and owner -> tickets indexes in step with a ticket write.
+/// Refunded tickets stay listed under their event but are no longer owned by anyone.
+fn update_ticket_indexes(env: &Env, ticket_id: u64, previous: Option<&Ticket>, ticket: &Ticket) {
+    if previous.is_none() {
+        index_insert(env, &(EVENT_TICKETS_PREFIX, ticket.event_id), ticket_id);
+    }
+
+    let old_owner = previous
+        .filter(|previous| !previous.refunded)
+        .map(|previous| previous.owner.clone());
+    let new_owner = (!ticket.refunded).then(|| ticket.owner.clone());
+    if old_owner == new_owner {
+        return;
+    }
+
+    if let Some(owner) = old_owner {
+        index_remove(env, &(OWNER_TICKETS_PREFIX, owner), ticket_id);
+    }
+    if let Some(owner) = new_owner {
+        index_insert(env, &(OWNER_TICKETS_PREFIX, owner), ticket_id);
+    }
 }
 
 /// Get ticket data
 pub fn get_ticket(env: &Env, ticket_id: u64) -> Result<Ticket, LumentixError> {
     let key = (TICKET_PREFIX, ticket_id);
+    let ticket = get_persistent(env, &key).ok_or(LumentixError::TicketNotFound)?;
+    extend_instance(env);
+    Ok(ticket)
+}
+
+/// Remove and return ticket data stored in the schema version 1 layout
+pub fn take_legacy_ticket(env: &Env, ticket_id: u64) -> Option<TicketV1> {
+    let key = (TICKET_PREFIX, ticket_id);
+    let legacy = env.storage().persistent().get(&key);
+    env.storage().persistent().remove(&key);
+    legacy
+}
+
+/// Get a page of the events created by an organizer, in creation order
+/// Pass the number of IDs already read as `cursor` (0 for the first page)
+pub fn get_organizer_events(env: &Env, organizer: &Address, cursor: u32, limit: u32) -> Vec<u64> {
+    paginate(
+        env,
+        &(ORGANIZER_EVENTS_PREFIX, organizer.clone()),
+        cursor,
+        limit,
+    )
+}
+
+/// Get a page of the unrefunded tickets owned by an address, in the order acquired
+/// Pages cover index positions rather than tickets: a ticket that is transferred
+/// away or refunded leaves its position empty, so a page can hold fewer than
+/// `limit` IDs. Advance the cursor by the page size (at most MAX_PAGE_SIZE)
+/// until it reaches `get_owner_ticket_positions`; no ticket is skipped or
+/// repeated while the owner's tickets change between pages.
+pub fn get_owner_tickets(env: &Env, owner: &Address, cursor: u32, limit: u32) -> Vec<u64> {
+    paginate(env, &(OWNER_TICKETS_PREFIX, owner.clone()), cursor, limit)
+}
+
+/// Get the number of positions in an owner's ticket index (the end cursor
+/// for `get_owner_tickets`)
+pub fn get_owner_ticket_positions(env: &Env, owner: &Address) -> u32 {
+    index_len(env, &(OWNER_TICKETS_PREFIX, owner.clone()))
+}
+
+/// Get the number of tickets issued for an event (including refunded ones)
+pub fn get_event_ticket_count(env: &Env, event_id: u64) -> u32 {
+    index_len(env, &(EVENT_TICKETS_PREFIX, event_id))
+}
+
+/// Get a page of the tickets issued for an event (including refunded ones), in issue order
+pub fn get_event_tickets(env: &Env, event_id: u64, cursor: u32, limit: u32) -> Vec<u64> {
+    paginate(env, &(EVENT_TICKETS_PREFIX, event_id), cursor, limit)
+}
+
+/// Get the number of positions used under an index key
+/// Each ID is its own entry under (key, INDEX_ITEM, position), with its
+/// position under (key, INDEX_SLOT, id), so writes cost the same however
+/// long an index grows. Positions are never reused, so the count includes
+/// those of removed IDs.
+fn index_len<K>(env: &Env, key: &K) -> u32
+where
+    K: IntoVal<Env, Val>,
+{
+    get_persistent(env, key).unwrap_or(0)
+}
+
+/// Key of the entry holding the ID at a position of an index
+fn index_item_key<K>(env: &Env, key: &K, position: u32) -> (Val, &'static str, u32)
+where
+    K: IntoVal<Env, Val>,
+{
+    (key.into_val(env), INDEX_ITEM, position)
+}
+
+/// Key of the entry holding the position of an ID in an index
+fn index_slot_key<K>(env: &Env, key: &K, id: u64) -> (Val, &'static str, u64)
+where
+    K: IntoVal<Env, Val>,
+{
+    (key.into_val(env), INDEX_SLOT, id)
+}
+
+/// Get the ID at a position of an index
+fn index_get<K>(env: &Env, key: &K, position: u32) -> Option<u64>
+where
+    K: IntoVal<Env, Val>,
+{
+    get_persistent(env, &index_item_key(env, key, position))
+}
+
+/// Append an ID to an index (no-op if already present)
+fn index_insert<K>(env: &Env, key: &K, id: u64)
+where
+    K: IntoVal<Env, Val>,
+{
+    let slot_key = index_slot_key(env, key, id);
+    if env.storage().persistent().has(&slot_key) {
+        return;
+    }
+
+    let position = index_len(env, key);
+    let item_key = index_item_key(env, key, position);
+    set_persistent(env, &item_key, &id);
+    set_persistent(env, &slot_key, &position);
+    set_persistent(env, key, &(position + 1));
+}
+
+/// Remove an ID from an index, leaving its position empty
+/// Other IDs keep their positions, so cursors held by paginating readers stay valid
+fn index_remove<K>(env: &Env, key: &K, id: u64)
+where
+    K: IntoVal<Env, Val>,
+{
+    let slot_key = index_slot_key(env, key, id);
+    let position: u32 = match get_persistent(env, &slot_key) {
+        Some(position) => position,
+        None => return,
+    };
+
     env.storage()
         .persistent()
-        .get(&key)
-        .ok_or(LumentixError::TicketNotFound)
+        .remove(&index_item_key(env, key, position));
+    env.storage().persistent().remove(&slot_key);
 }
 
-/// Add amount to escrow for an event
-pub fn add_escrow(env: &Env, event_id: u64, amount: i128) {
-    let key = (ESCROW_PREFIX, event_id);
-    let current: i128 = env.storage().persistent().get(&key).unwrap_or(0);
-    env.storage().persistent().set(&key, &(current + amount));
+/// Return the IDs at up to `limit` (at most MAX_PAGE_SIZE) positions of an index
+/// starting at position `cursor`, skipping empty positions
+fn paginate<K>(env: &Env, key: &K, cursor: u32, limit: u32) -> Vec<u64>
+where
+    K: IntoVal<Env, Val>,
+{
+    let end = cursor
+        .saturating_add(limit.min(MAX_PAGE_SIZE))
+        .min(index_len(env, key));
+    let mut ids = Vec::new(env);
+    for position in cursor..end {
+        if let Some(id) = index_get(env, key, position) {
+            ids.push_back(id);
+        }
+    }
+    ids
 }
 
-/// Get escrow balance for an event
-pub fn get_escrow(env: &Env, event_id: u64) -> Result<i128, LumentixError> {
-    let key = (ESCROW_PREFIX, event_id);
-    Ok(env.storage().persistent().get(&key).unwrap_or(0))
+/// Set the revenue split for an event
+pub fn set_revenue_split(env: &Env, event_id: u64, split: &RevenueSplit) {
+    let key = (REVENUE_SPLIT_PREFIX, event_id);
+    set_persistent(env, &key, split);
+}
+
+/// Get the revenue split for an event (None pays everything to the organizer)
+pub fn get_revenue_split(env: &Env, event_id: u64) -> Option<RevenueSplit> {
+    let key = (REVENUE_SPLIT_PREFIX, event_id);
+    get_persistent(env, &key)
+}
+
+/// Set resale policy for an event
+pub fn set_resale_policy(env: &Env, event_id: u64, policy: &ResalePolicy) {
+    let key = (RESALE_POLICY_PREFIX, event_id);
+    set_persistent(env, &key, policy);
 }
 
-/// Deduct amount from escrow
-pub fn deduct_escrow(env: &Env, event_id: u64, amount: i128) -> Result<(), LumentixError> {
+/// Get resale policy for an event (resale is disabled until one is set)
+pub fn get_resale_policy(env: &Env, event_id: u64) -> Result<ResalePolicy, LumentixError> {
+    let key = (RESALE_POLICY_PREFIX, event_id);
+    get_persistent(env, &key).ok_or(LumentixError::ResaleNotAllowed)
+}
+
+/// Set resale listing for a ticket
+pub fn set_resale_listing(env: &Env, listing: &ResaleListing) {
+    let key = (RESALE_LISTING_PREFIX, listing.ticket_id);
+    set_persistent(env, &key, listing);
+}
+
+/// Get resale listing for a ticket
+pub fn get_resale_listing(env: &Env, ticket_id: u64) -> Result<ResaleListing, LumentixError> {
+    let key = (RESALE_LISTING_PREFIX, ticket_id);
+    get_persistent(env, &key).ok_or(LumentixError::TicketNotListed)
+}
+
+/// Check if a ticket is currently listed for resale
+pub fn is_listed_for_resale(env: &Env, ticket_id: u64) -> bool {
+    let key = (RESALE_LISTING_PREFIX, ticket_id);
+    env.storage().persistent().has(&key)
+}
+
+/// Remove resale listing (after sale or cancellation)
+pub fn remove_resale_listing(env: &Env, ticket_id: u64) {
+    let key = (RESALE_LISTING_PREFIX, ticket_id);
+    env.storage().persistent().remove(&key);
+}
+
+/// Maximum number of addresses on an event's waitlist (bounds the stored list)
+pub const MAX_WAITLIST_SIZE: u32 = 200;
+
+/// Get the FIFO waitlist for an event
+pub fn get_waitlist(env: &Env, event_id: u64) -> Vec<Address> {
+    let key = (WAITLIST_PREFIX, event_id);
+    get_persistent(env, &key).unwrap_or_else(|| Vec::new(env))
+}
+
+/// Append an address to the back of an event's waitlist
+pub fn join_waitlist(env: &Env, event_id: u64, buyer: &Address) -> Result<u32, LumentixError> {
+    let mut waitlist = get_waitlist(env, event_id);
+    if waitlist.contains(buyer) {
+        return Err(LumentixError::AlreadyOnWaitlist);
+    }
+    if waitlist.len() >= MAX_WAITLIST_SIZE {
+        return Err(LumentixError::WaitlistFull);
+    }
+
+    waitlist.push_back(buyer.clone());
+    let key = (WAITLIST_PREFIX, event_id);
+    set_persistent(env, &key, &waitlist);
+    Ok(waitlist.len())
+}
+
+/// Remove an address from an event's waitlist
+pub fn leave_waitlist(env: &Env, event_id: u64, buyer: &Address) -> Result<(), LumentixError> {
+    let mut waitlist = get_waitlist(env, event_id);
+    let index = waitlist
+        .first_index_of(buyer)
+        .ok_or(LumentixError::NotOnWaitlist)?;
+
+    waitlist.remove(index);
+    let key = (WAITLIST_PREFIX, event_id);
+    set_persistent(env, &key, &waitlist);
+    Ok(())
+}
+
+/// Pop the next address from the front of an event's waitlist
+pub fn pop_waitlist(env: &Env, event_id: u64) -> Option<Address> {
+    let mut waitlist = get_waitlist(env, event_id);
+    let next = waitlist.pop_front();
+
+    let key = (WAITLIST_PREFIX, event_id);
+    set_persistent(env, &key, &waitlist);
+    next
+}
+
+/// Set the open waitlist offer for an event
+pub fn set_waitlist_offer(env: &Env, offer: &WaitlistOffer) {
+    let key = (WAITLIST_OFFER_PREFIX, offer.event_id);
+    set_persistent(env, &key, offer);
+}
+
+/// Get the open waitlist offer for an event, if any
+pub fn get_waitlist_offer(env: &Env, event_id: u64) -> Option<WaitlistOffer> {
+    let key = (WAITLIST_OFFER_PREFIX, event_id);
+    get_persistent(env, &key)
+}
+
+/// Remove the open waitlist offer (accepted or expired)
+pub fn remove_waitlist_offer(env: &Env, event_id: u64) {
+    let key = (WAITLIST_OFFER_PREFIX, event_id);
+    env.storage().persistent().remove(&key);
+}
+
+/// Get the roles an address holds for an event
+pub fn get_roles(env: &Env, event_id: u64, account: &Address) -> Vec<EventRole> {
+    let key = (ROLE_PREFIX, event_id, account.clone());
+    get_persistent(env, &key).unwrap_or_else(|| Vec::new(env))
+}
+
+/// Check if an address holds a role for an event
+pub fn has_role(env: &Env, event_id: u64, account: &Address, role: EventRole) -> bool {
+    get_roles(env, event_id, account).contains(role)
+}
+
+/// Grant a role for an event to an address (no-op if already granted)
+pub fn grant_role(env: &Env, event_id: u64, account: &Address, role: EventRole) {
+    let mut roles = get_roles(env, event_id, account);
+    if !roles.contains(role) {
+        roles.push_back(role);
+        let key = (ROLE_PREFIX, event_id, account.clone());
+        set_persistent(env, &key, &roles);
+    }
+}
+
+/// Revoke a role for an event from an address
+pub fn revoke_role(env: &Env, event_id: u64, account: &Address, role: EventRole) {
+    let mut roles = get_roles(env, event_id, account);
+    if let Some(index) = roles.first_index_of(role) {
+        roles.remove(index);
+        let key = (ROLE_PREFIX, event_id, account.clone());
+        if roles.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            set_persistent(env, &key, &roles);
+        }
+    }
+}
+
+/// Require that `caller` is the event organizer or holds `role` for the event
+pub fn require_role(
+    env: &Env,
+    event: &Event,
+    caller: &Address,
+    role: EventRole,
+) -> Result<(), LumentixError> {
+    if event.organizer == *caller || has_role(env, event.id, caller, role) {
+        Ok(())
+    } else {
+        Err(LumentixError::Unauthorized)
+    }
+}
+
+/// Set postponement details for an event
+pub fn set_postponement(env: &Env, postponement: &Postponement) {
+    let key = (POSTPONEMENT_PREFIX, postponement.event_id);
+    set_persistent(env, &key, postponement);
+}
+
+/// Get postponement details for an event
+pub fn get_postponement(env: &Env, event_id: u64) -> Result<Postponement, LumentixError> {
+    let key = (POSTPONEMENT_PREFIX, event_id);
+    get_persistent(env, &key).ok_or(LumentixError::PostponementNotFound)
+}
+
+/// Set promo code for an event
+pub fn set_promo_code(env: &Env, event_id: u64, promo: &PromoCode) {
+    let key = (PROMO_PREFIX, event_id, promo.code_hash.clone());
+    set_persistent(env, &key, promo);
+}
+
+/// Get promo code for an event by the hash of the code
+pub fn get_promo_code(
+    env: &Env,
+    event_id: u64,
+    code_hash: &BytesN<32>,
+) -> Result<PromoCode, LumentixError> {
+    let key = (PROMO_PREFIX, event_id, code_hash.clone());
+    get_persistent(env, &key).ok_or(LumentixError::PromoCodeNotFound)
+}
+
+/// Set the allowlist presale configuration for an event
+pub fn set_presale_config(env: &Env, event_id: u64, presale: &PresaleConfig) {
+    let key = (PRESALE_CONFIG_PREFIX, event_id);
+    set_persistent(env, &key, presale);
+}
+
+/// Get the allowlist presale configuration for an event (None: public sale only)
+pub fn get_presale_config(env: &Env, event_id: u64) -> Option<PresaleConfig> {
+    let key = (PRESALE_CONFIG_PREFIX, event_id);
+    get_persistent(env, &key)
+}
+
+/// Get number of presale tickets bought by an address for an event
+pub fn get_presale_purchases(env: &Env, event_id: u64, buyer: &Address) -> u32 {
+    let key = (PRESALE_PREFIX, event_id, buyer.clone());
+    get_persistent(env, &key).unwrap_or(0)
+}
+
+/// Record presale tickets bought by an address for an event
+pub fn add_presale_purchases(env: &Env, event_id: u64, buyer: &Address, quantity: u32) {
+    let key = (PRESALE_PREFIX, event_id, buyer.clone());
+    let current = get_presale_purchases(env, event_id, buyer);
+    set_persistent(env, &key, &(current + quantity));
+}
+
+/// Set the dynamic pricing curve for an event
+pub fn set_pricing_curve(env: &Env, event_id: u64, curve: &PricingCurve) {
+    let key = (PRICING_CURVE_PREFIX, event_id);
+    set_persistent(env, &key, curve);
+}
+
+/// Get the dynamic pricing curve for an event (None: flat tier pricing)
+pub fn get_pricing_curve(env: &Env, event_id: u64) -> Option<PricingCurve> {
+    let key = (PRICING_CURVE_PREFIX, event_id);
+    get_persistent(env, &key)
+}
+
+/// Set the Dutch auction pricing for an event
+pub fn set_auction(env: &Env, event_id: u64, auction: &DutchAuction) {
+    let key = (AUCTION_PREFIX, event_id);
+    set_persistent(env, &key, auction);
+}
+
+/// Get the Dutch auction pricing for an event (None: fixed or curve pricing)
+pub fn get_auction(env: &Env, event_id: u64) -> Option<DutchAuction> {
+    let key = (AUCTION_PREFIX, event_id);
+    get_persistent(env, &key)
+}
+
+/// Record the lowest price paid so far in an event's auction
+pub fn record_auction_price(env: &Env, event_id: u64, price: i128) {
+    let key = (CLEARING_PRICE_PREFIX, event_id);
+    let current: Option<i128> = get_persistent(env, &key);
+    if current.is_none_or(|lowest| price < lowest) {
+        set_persistent(env, &key, &price);
+    }
+}
+
+/// Get the auction clearing price (lowest price paid) for an event
+pub fn get_clearing_price(env: &Env, event_id: u64) -> Option<i128> {
+    let key = (CLEARING_PRICE_PREFIX, event_id);
+    get_persistent(env, &key)
+}
+
+/// Check if the clearing price rebate was claimed for a ticket
+pub fn is_rebate_claimed(env: &Env, ticket_id: u64) -> bool {
+    let key = (REBATE_PREFIX, ticket_id);
+    get_persistent(env, &key).unwrap_or(false)
+}
+
+/// Mark the clearing price rebate as claimed for a ticket
+pub fn set_rebate_claimed(env: &Env, ticket_id: u64) {
+    let key = (REBATE_PREFIX, ticket_id);
+    set_persistent(env, &key, &true);
+}
+
+/// Pay a ticket's uniform clearing rebate out of escrow and the event's pending
+/// fees, and reprice the ticket at the clearing price so later refunds use it.
+/// Returns the total owed to the holder (0 without a uniform clearing auction).
+pub fn claim_clearing_rebate(env: &Env, ticket_id: u64, now: u64) -> Result<i128, LumentixError> {
+    if is_rebate_claimed(env, ticket_id) {
+        return Err(LumentixError::RebateAlreadyClaimed);
+    }
+    let mut ticket = get_ticket(env, ticket_id)?;
+    let event = get_event(env, ticket.event_id)?;
+    let Some(auction) = get_auction(env, event.id) else {
+        return Ok(0);
+    };
+    let clearing_price = get_clearing_price(env, event.id).ok_or(LumentixError::AuctionNotEnded)?;
+    let (escrow_rebate, fee_rebate) =
+        calculate_clearing_rebate(&event, &auction, &ticket, clearing_price, now)?;
+
+    let token = ticket.payment_token.clone();
+    deduct_escrow(env, event.id, ticket.tier_id, &token, escrow_rebate)?;
+    reverse_platform_fee(env, event.id, &token, fee_rebate)?;
+    ticket.price_paid -= escrow_rebate + fee_rebate;
+    ticket.platform_fee -= fee_rebate;
+    set_ticket(env, ticket_id, &ticket);
+    set_rebate_claimed(env, ticket_id);
+    Ok(escrow_rebate + fee_rebate)
+}
+
+/// Remove and return an event's escrow stored under the schema version 1 key
+pub fn take_legacy_escrow(env: &Env, event_id: u64) -> i128 {
     let key = (ESCROW_PREFIX, event_id);
-    let current: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    let amount: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage().persistent().remove(&key);
+    amount
+}
 
-    if current < amount {
+/// Add amount to escrow for an event in the token it was paid with,
+/// tracked against the ticket tier it was paid for
+pub fn add_escrow(env: &Env, event_id: u64, tier_id: u32, token: &Address, amount: i128) {
+    let key = (ESCROW_PREFIX, event_id, token.clone());
+    let current: i128 = get_persistent(env, &key).unwrap_or(0);
+    set_persistent(env, &key, &(current + amount));
+
+    let tier_key = (TIER_ESCROW_PREFIX, event_id, tier_id, token.clone());
+    let tier_current: i128 = get_persistent(env, &tier_key).unwrap_or(0);
+    set_persistent(env, &tier_key, &(tier_current + amount));
+}
+
+/// Get escrow balance for an event in a payment token
+pub fn get_escrow(env: &Env, event_id: u64, token: &Address) -> Result<i128, LumentixError> {
+    let key = (ESCROW_PREFIX, event_id, token.clone());
+    Ok(get_persistent(env, &key).unwrap_or(0))
+}
+
+/// Get escrow balance held for a single ticket tier of an event in a payment token
+pub fn get_tier_escrow(env: &Env, event_id: u64, tier_id: u32, token: &Address) -> i128 {
+    let key = (TIER_ESCROW_PREFIX, event_id, tier_id, token.clone());
+    get_persistent(env, &key).unwrap_or(0)
+}
+
+/// Deduct amount from escrow (e.g. a refund for a ticket of the given tier)
+pub fn deduct_escrow(
+    env: &Env,
+    event_id: u64,
+    tier_id: u32,
+    token: &Address,
+    amount: i128,
+) -> Result<(), LumentixError> {
+    let key = (ESCROW_PREFIX, event_id, token.clone());
+    let current: i128 = get_persistent(env, &key).unwrap_or(0);
+
+    let tier_key = (TIER_ESCROW_PREFIX, event_id, tier_id, token.clone());
+    let tier_current: i128 = get_persistent(env, &tier_key).unwrap_or(0);
+
+    if current < amount || tier_current < amount {
         return Err(LumentixError::InsufficientEscrow);
     }
 
-    env.storage().persistent().set(&key, &(current - amount));
+    set_persistent(env, &key, &(current - amount));
+    set_persistent(env, &tier_key, &(tier_current - amount));
+
     Ok(())
 }
 
-/// Clear escrow for an event
-pub fn clear_escrow(env: &Env, event_id: u64) {
-    let key = (ESCROW_PREFIX, event_id);
-    env.storage().persistent().set(&key, &0i128);
+/// Clear escrow for an event and all of its ticket tiers in a payment token
+pub fn clear_escrow(env: &Env, event: &Event, token: &Address) {
+    let key = (ESCROW_PREFIX, event.id, token.clone());
+    set_persistent(env, &key, &0i128);
+
+    for tier in event.tiers.iter() {
+        let tier_key = (TIER_ESCROW_PREFIX, event.id, tier.id, token.clone());
+        set_persistent(env, &tier_key, &0i128);
+    }
+}
+
+/// Set the admin-approved escrow advance policy for an event
+pub fn set_advance_policy(env: &Env, event_id: u64, policy: &AdvancePolicy) {
+    let key = (ADVANCE_POLICY_PREFIX, event_id);
+    set_persistent(env, &key, policy);
+}
+
+/// Get the escrow advance policy for an event (None: no advances before completion)
+pub fn get_advance_policy(env: &Env, event_id: u64) -> Option<AdvancePolicy> {
+    let key = (ADVANCE_POLICY_PREFIX, event_id);
+    get_persistent(env, &key)
+}
+
+/// Get the total escrow advanced to the organizer of an event in a token
+pub fn get_advanced(env: &Env, event_id: u64, token: &Address) -> i128 {
+    let key = (ADVANCED_PREFIX, event_id, token.clone());
+    get_persistent(env, &key).unwrap_or(0)
+}
+
+/// Move an advance out of an event's escrow balance and record it
+/// Tier balances are left untouched: they track what each tier's holders paid in.
+/// The advance is paid out like a release, through `calculate_escrow_payouts`
+pub fn take_advance(
+    env: &Env,
+    event_id: u64,
+    token: &Address,
+    amount: i128,
+) -> Result<i128, LumentixError> {
+    let key = (ESCROW_PREFIX, event_id, token.clone());
+    let current: i128 = get_persistent(env, &key).unwrap_or(0);
+    if current < amount {
+        return Err(LumentixError::InsufficientEscrow);
+    }
+    set_persistent(env, &key, &(current - amount));
+
+    let advanced_key = (ADVANCED_PREFIX, event_id, token.clone());
+    let advanced = get_advanced(env, event_id, token) + amount;
+    set_persistent(env, &advanced_key, &advanced);
+    Ok(advanced)
+}
+
+/// Return advanced funds to an event's escrow (e.g. so cancellation refunds are covered)
+pub fn repay_advance(
+    env: &Env,
+    event_id: u64,
+    token: &Address,
+    amount: i128,
+) -> Result<i128, LumentixError> {
+    let advanced = get_advanced(env, event_id, token);
+    if amount > advanced {
+        return Err(LumentixError::InvalidAmount);
+    }
+
+    let key = (ESCROW_PREFIX, event_id, token.clone());
+    let current: i128 = get_persistent(env, &key).unwrap_or(0);
+    set_persistent(env, &key, &(current + amount));
+
+    let advanced_key = (ADVANCED_PREFIX, event_id, token.clone());
+    set_persistent(env, &advanced_key, &(advanced - amount));
+    Ok(advanced - amount)
+}
+
+/// Get the number of tickets checked in for an event
+pub fn get_checkins(env: &Env, event_id: u64) -> u32 {
+    let key = (CHECKIN_COUNT_PREFIX, event_id);
+    get_persistent(env, &key).unwrap_or(0)
+}
+
+/// Count a ticket check-in for an event
+pub fn increment_checkins(env: &Env, event_id: u64) {
+    let key = (CHECKIN_COUNT_PREFIX, event_id);
+    let next = get_checkins(env, event_id) + 1;
+    set_persistent(env, &key, &next);
+}
+
+/// Set the post-event dispute window in seconds
+pub fn set_dispute_window(env: &Env, window_secs: u64) {
+    env.storage().instance().set(&DISPUTE_WINDOW, &window_secs);
+}
+
+/// Get the post-event dispute window in seconds (DEFAULT_DISPUTE_WINDOW until set)
+pub fn get_dispute_window(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DISPUTE_WINDOW)
+        .unwrap_or(DEFAULT_DISPUTE_WINDOW)
+}
+
+/// Set the dispute for an event (opened or resolved)
+pub fn set_dispute(env: &Env, dispute: &Dispute) {
+    let key = (DISPUTE_PREFIX, dispute.event_id);
+    set_persistent(env, &key, dispute);
+}
+
+/// Get the dispute for an event, if one was opened
+pub fn get_dispute(env: &Env, event_id: u64) -> Option<Dispute> {
+    let key = (DISPUTE_PREFIX, event_id);
+    get_persistent(env, &key)
+}
+
+/// Maximum number of tickets `bump_event` extends in one invocation
+/// Each ticket touches up to eight entries (ticket, resale listing, rebate
+/// flag and its event and owner index entries)
+pub const MAX_BUMP_PAGE_SIZE: u32 = 8;
+
+/// Extend an event, its event-scoped entries (escrow, fees, payout and sale
+/// configuration) and a page of its tickets so none of them get archived
+/// before the event settles. Pass the number of tickets already extended as
+/// `cursor` (0 for the first page); returns the number extended by this call.
+pub fn bump_event(env: &Env, event_id: u64, cursor: u32, limit: u32) -> Result<u32, LumentixError> {
+    let event = get_event(env, event_id)?;
+
+    let mut tokens = Vec::from_array(env, [get_token(env)]);
+    for accepted in event.accepted_tokens.iter() {
+        if !tokens.contains(&accepted.token) {
+            tokens.push_back(accepted.token);
+        }
+    }
+
+    extend_index_entry(
+        env,
+        &(ORGANIZER_EVENTS_PREFIX, event.organizer.clone()),
+        event_id,
+    );
+    extend_persistent(env, &(REVENUE_SPLIT_PREFIX, event_id));
+    extend_persistent(env, &(RESALE_POLICY_PREFIX, event_id));
+    extend_persistent(env, &(WAITLIST_PREFIX, event_id));
+    extend_persistent(env, &(WAITLIST_OFFER_PREFIX, event_id));
+    extend_persistent(env, &(POSTPONEMENT_PREFIX, event_id));
+    extend_persistent(env, &(PRESALE_CONFIG_PREFIX, event_id));
+    extend_persistent(env, &(PRICING_CURVE_PREFIX, event_id));
+    extend_persistent(env, &(AUCTION_PREFIX, event_id));
+    extend_persistent(env, &(CLEARING_PRICE_PREFIX, event_id));
+    extend_persistent(env, &(ADVANCE_POLICY_PREFIX, event_id));
+    extend_persistent(env, &(CHECKIN_COUNT_PREFIX, event_id));
+    extend_persistent(env, &(DISPUTE_PREFIX, event_id));
+    extend_persistent(env, &(EVENT_FEE_PREFIX, event_id));
+
+    let ticket_ids_key = (EVENT_TICKETS_PREFIX, event_id);
+    let end = cursor
+        .saturating_add(limit.min(MAX_BUMP_PAGE_SIZE))
+        .min(index_len(env, &ticket_ids_key));
+    extend_persistent(env, &ticket_ids_key);
+
+    let mut extended = 0;
+    for position in cursor..end {
+        let Some(ticket_id) = index_get(env, &ticket_ids_key, position) else {
+            continue;
+        };
+        extend_index_entry(env, &ticket_ids_key, ticket_id);
+        extend_persistent(env, &(RESALE_LISTING_PREFIX, ticket_id));
+        extend_persistent(env, &(REBATE_PREFIX, ticket_id));
+
+        let ticket: Option<Ticket> = get_persistent(env, &(TICKET_PREFIX, ticket_id));
+        if let Some(ticket) = ticket {
+            extend_index_entry(
+                env,
+                &(OWNER_TICKETS_PREFIX, ticket.owner.clone()),
+                ticket_id,
+            );
+            if !tokens.contains(&ticket.payment_token) {
+                tokens.push_back(ticket.payment_token);
+            }
+        }
+        extended += 1;
+    }
+
+    for token in tokens.iter() {
+        extend_persistent(env, &(ESCROW_PREFIX, event_id, token.clone()));
+        extend_persistent(env, &(EVENT_FEES_PREFIX, event_id, token.clone()));
+        extend_persistent(env, &(ADVANCED_PREFIX, event_id, token.clone()));
+        for tier in event.tiers.iter() {
+            extend_persistent(env, &(TIER_ESCROW_PREFIX, event_id, tier.id, token.clone()));
+        }
+    }
+    Ok(extended)
+}
+
+/// Extend an index's length entry and the entries holding one of its IDs
+fn extend_index_entry<K>(env: &Env, key: &K, id: u64)
+where
+    K: IntoVal<Env, Val>,
+{
+    let slot_key = index_slot_key(env, key, id);
+    let position: Option<u32> = get_persistent(env, &slot_key);
+    if let Some(position) = position {
+        extend_persistent(env, key);
+        extend_persistent(env, &slot_key);
+        extend_persistent(env, &index_item_key(env, key, position));
+    }
 }
 
 /// Set platform fee in basis points (e.g., 250 = 2.5%)
@@ -141,18 +1160,1065 @@ pub fn get_platform_fee_bps(env: &Env) -> u32 {
     env.storage().instance().get(&PLATFORM_FEE_BPS).unwrap_or(0)
 }
 
-/// Add amount to platform balance
-pub fn add_platform_balance(env: &Env, amount: i128) {
-    let current: i128 = env.storage().instance().get(&PLATFORM_BALANCE).unwrap_or(0);
-    env.storage().instance().set(&PLATFORM_BALANCE, &(current + amount));
+/// Set a platform fee override for all events of an organizer
+/// In multi-admin mode this needs an approved AdminAction::SetOrganizerFee
+pub fn set_organizer_fee_bps(
+    env: &Env,
+    organizer: &Address,
+    fee_bps: u32,
+) -> Result<(), LumentixError> {
+    validate_bps(fee_bps)?;
+    let action = AdminAction::SetOrganizerFee(organizer.clone(), fee_bps);
+    require_approved(env, &action)?;
+    clear_approvals(env, &action);
+
+    let key = (ORGANIZER_FEE_PREFIX, organizer.clone());
+    set_persistent(env, &key, &fee_bps);
+    Ok(())
+}
+
+/// Get the platform fee override for an organizer, if any
+pub fn get_organizer_fee_bps(env: &Env, organizer: &Address) -> Option<u32> {
+    let key = (ORGANIZER_FEE_PREFIX, organizer.clone());
+    get_persistent(env, &key)
+}
+
+/// Remove the platform fee override for an organizer
+/// In multi-admin mode this needs an approved AdminAction::RemoveOrganizerFee
+pub fn remove_organizer_fee_bps(env: &Env, organizer: &Address) -> Result<(), LumentixError> {
+    let action = AdminAction::RemoveOrganizerFee(organizer.clone());
+    require_approved(env, &action)?;
+    clear_approvals(env, &action);
+
+    let key = (ORGANIZER_FEE_PREFIX, organizer.clone());
+    env.storage().persistent().remove(&key);
+    Ok(())
+}
+
+/// Set a platform fee override for a single event (e.g. 0 for charity events)
+/// In multi-admin mode this needs an approved AdminAction::SetEventFee
+pub fn set_event_fee_bps(env: &Env, event_id: u64, fee_bps: u32) -> Result<(), LumentixError> {
+    validate_bps(fee_bps)?;
+    let action = AdminAction::SetEventFee(event_id, fee_bps);
+    require_approved(env, &action)?;
+    clear_approvals(env, &action);
+
+    let key = (EVENT_FEE_PREFIX, event_id);
+    set_persistent(env, &key, &fee_bps);
+    Ok(())
+}
+
+/// Get the platform fee override for an event, if any
+pub fn get_event_fee_bps(env: &Env, event_id: u64) -> Option<u32> {
+    let key = (EVENT_FEE_PREFIX, event_id);
+    get_persistent(env, &key)
+}
+
+/// Remove the platform fee override for an event
+/// In multi-admin mode this needs an approved AdminAction::RemoveEventFee
+pub fn remove_event_fee_bps(env: &Env, event_id: u64) -> Result<(), LumentixError> {
+    let action = AdminAction::RemoveEventFee(event_id);
+    require_approved(env, &action)?;
+    clear_approvals(env, &action);
+
+    let key = (EVENT_FEE_PREFIX, event_id);
+    env.storage().persistent().remove(&key);
+    Ok(())
+}
+
+/// Resolve the platform fee for an event: event override, then organizer
+/// override, then the global PLATFORM_FEE_BPS
+pub fn get_effective_fee_bps(env: &Env, event: &Event) -> u32 {
+    get_event_fee_bps(env, event.id)
+        .or_else(|| get_organizer_fee_bps(env, &event.organizer))
+        .unwrap_or_else(|| get_platform_fee_bps(env))
+}
+
+/// Remove and return the platform balance stored under the schema version 1 key
+pub fn take_legacy_platform_balance(env: &Env) -> i128 {
+    let amount: i128 = env.storage().instance().get(&PLATFORM_BALANCE).unwrap_or(0);
+    env.storage().instance().remove(&PLATFORM_BALANCE);
+    amount
+}
+
+/// Credit already-settled (withdrawable) platform balance in a token
+pub fn credit_platform_balance(env: &Env, token: &Address, amount: i128) {
+    let current = get_platform_balance(env, token);
+    env.storage()
+        .instance()
+        .set(&(PLATFORM_BALANCE, token.clone()), &(current + amount));
+}
+
+/// Add a platform fee collected for an event to the platform balance of a token
+/// The fee stays pending (reversible by cancellation refunds) until the event settles
+pub fn add_platform_balance(env: &Env, event_id: u64, token: &Address, amount: i128) {
+    let current = get_platform_balance(env, token);
+    env.storage()
+        .instance()
+        .set(&(PLATFORM_BALANCE, token.clone()), &(current + amount));
+
+    let pending = get_pending_fees(env, token);
+    env.storage()
+        .instance()
+        .set(&(PENDING_FEES, token.clone()), &(pending + amount));
+
+    let key = (EVENT_FEES_PREFIX, event_id, token.clone());
+    let event_fees = get_event_fees(env, event_id, token);
+    set_persistent(env, &key, &(event_fees + amount));
+}
+
+/// Get platform balance in a token
+pub fn get_platform_balance(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&(PLATFORM_BALANCE, token.clone()))
+        .unwrap_or(0)
+}
+
+/// Get total platform fees in a token that are still pending (events not yet settled)
+pub fn get_pending_fees(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&(PENDING_FEES, token.clone()))
+        .unwrap_or(0)
+}
+
+/// Get pending platform fees collected for an event in a token
+pub fn get_event_fees(env: &Env, event_id: u64, token: &Address) -> i128 {
+    let key = (EVENT_FEES_PREFIX, event_id, token.clone());
+    get_persistent(env, &key).unwrap_or(0)
+}
+
+/// Get the part of the platform balance in a token that can be withdrawn
+pub fn get_withdrawable_platform_balance(env: &Env, token: &Address) -> i128 {
+    get_platform_balance(env, token) - get_pending_fees(env, token)
+}
+
+/// Claw back a platform fee for a cancellation refund
+/// Fails rather than letting the event fee total or platform balance go negative
+pub fn reverse_platform_fee(
+    env: &Env,
+    event_id: u64,
+    token: &Address,
+    amount: i128,
+) -> Result<(), LumentixError> {
+    let current = get_platform_balance(env, token);
+    let pending = get_pending_fees(env, token);
+    let event_fees = get_event_fees(env, event_id, token);
+
+    if event_fees < amount || pending < amount || current < amount {
+        return Err(LumentixError::InsufficientPlatformBalance);
+    }
+
+    env.storage()
+        .instance()
+        .set(&(PLATFORM_BALANCE, token.clone()), &(current - amount));
+    env.storage()
+        .instance()
+        .set(&(PENDING_FEES, token.clone()), &(pending - amount));
+    let key = (EVENT_FEES_PREFIX, event_id, token.clone());
+    set_persistent(env, &key, &(event_fees - amount));
+    Ok(())
+}
+
+/// Settle an event's fees in a token (on completion) so they become withdrawable
+pub fn settle_event_fees(env: &Env, event_id: u64, token: &Address) {
+    let event_fees = get_event_fees(env, event_id, token);
+    let pending = get_pending_fees(env, token);
+    env.storage()
+        .instance()
+        .set(&(PENDING_FEES, token.clone()), &(pending - event_fees));
+
+    let key = (EVENT_FEES_PREFIX, event_id, token.clone());
+    set_persistent(env, &key, &0i128);
 }
 
-/// Get platform balance
-pub fn get_platform_balance(env: &Env) -> i128 {
-    env.storage().instance().get(&PLATFORM_BALANCE).unwrap_or(0)
+/// Clear the withdrawable platform balance in a token (after withdrawal)
+/// Pending fees stay in the balance so cancellation refunds remain covered
+pub fn clear_platform_balance(env: &Env, token: &Address) {
+    let pending = get_pending_fees(env, token);
+    env.storage()
+        .instance()
+        .set(&(PLATFORM_BALANCE, token.clone()), &pending);
 }
 
-/// Clear platform balance (after withdrawal)
-pub fn clear_platform_balance(env: &Env) {
-    env.storage().instance().set(&PLATFORM_BALANCE, &0i128);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{DisputeResolution, EventStatus, TicketTier};
+    use crate::validation::{
+        calculate_dispute_release, calculate_dispute_shortfall, calculate_refund_shortfall,
+        MAX_BATCH_PURCHASE,
+    };
+    use soroban_sdk::{
+        contract,
+        testutils::{
+            storage::{Instance as _, Persistent as _},
+            Address as _, Ledger,
+        },
+        String,
+    };
+
+    #[contract]
+    struct StorageHost;
+
+    fn event(env: &Env) -> Event {
+        let general = TicketTier {
+            id: 0,
+            name: String::from_str(env, "General Admission"),
+            price: 100,
+            max_tickets: 50,
+            tickets_sold: 1,
+        };
+        Event {
+            id: 1,
+            organizer: Address::generate(env),
+            name: String::from_str(env, "Test Event"),
+            description: String::from_str(env, "Description"),
+            location: String::from_str(env, "Location"),
+            start_time: 1000,
+            end_time: 2000,
+            tiers: Vec::from_array(env, [general]),
+            refund_schedule: Vec::new(env),
+            accepted_tokens: Vec::new(env),
+            reference_currency: None,
+            status: EventStatus::Published,
+        }
+    }
+
+    fn ticket(env: &Env, id: u64, event_id: u64, token: &Address) -> Ticket {
+        Ticket {
+            id,
+            event_id,
+            tier_id: 0,
+            owner: Address::generate(env),
+            payment_token: token.clone(),
+            price_paid: 100,
+            platform_fee: 0,
+            purchase_time: 500,
+            used: false,
+            refunded: false,
+        }
+    }
+
+    fn jump(env: &Env, ledgers: u32) {
+        env.ledger().with_mut(|li| li.sequence_number += ledgers);
+    }
+
+    #[test]
+    fn test_reads_and_writes_extend_ttl() {
+        let env = Env::default();
+        let contract_id = env.register(StorageHost, ());
+
+        env.as_contract(&contract_id, || {
+            set_token(&env, &Address::generate(&env));
+            set_event(&env, 1, &event(&env));
+            let key = (EVENT_PREFIX, 1u64);
+            assert_eq!(
+                env.storage().persistent().get_ttl(&key),
+                DEFAULT_TTL_EXTEND_TO
+            );
+            assert_eq!(env.storage().instance().get_ttl(), DEFAULT_TTL_EXTEND_TO);
+
+            // Above the threshold nothing is extended
+            jump(&env, 20 * DAY_IN_LEDGERS);
+            get_event(&env, 1).unwrap();
+            assert_eq!(
+                env.storage().persistent().get_ttl(&key),
+                10 * DAY_IN_LEDGERS
+            );
+
+            // Below the threshold a read extends the entry again
+            jump(&env, 5 * DAY_IN_LEDGERS);
+            get_event(&env, 1).unwrap();
+            assert_eq!(
+                env.storage().persistent().get_ttl(&key),
+                DEFAULT_TTL_EXTEND_TO
+            );
+            assert_eq!(env.storage().instance().get_ttl(), DEFAULT_TTL_EXTEND_TO);
+
+            // Past the original 30 days the entry is still live
+            jump(&env, 25 * DAY_IN_LEDGERS);
+            assert!(get_event(&env, 1).is_ok());
+        });
+    }
+
+    #[test]
+    fn test_ttl_config_is_used() {
+        let env = Env::default();
+        let contract_id = env.register(StorageHost, ());
+        let config = TtlConfig {
+            threshold: DAY_IN_LEDGERS,
+            extend_to: 60 * DAY_IN_LEDGERS,
+        };
+
+        env.as_contract(&contract_id, || {
+            set_ttl_config(&env, &config);
+            let token = Address::generate(&env);
+            set_ticket(&env, 1, &ticket(&env, 1, 1, &token));
+            assert_eq!(
+                env.storage().persistent().get_ttl(&(TICKET_PREFIX, 1u64)),
+                config.extend_to
+            );
+        });
+    }
+
+    #[test]
+    fn test_reserve_ticket_ids_allocates_disjoint_blocks() {
+        let env = Env::default();
+        let contract_id = env.register(StorageHost, ());
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(get_next_ticket_id(&env), 1);
+
+            // A batch of 5 gets IDs 1..=5 and moves the counter past them
+            assert_eq!(reserve_ticket_ids(&env, 5), 1);
+            assert_eq!(get_next_ticket_id(&env), 6);
+
+            // The next batch starts right after the previous one
+            assert_eq!(reserve_ticket_ids(&env, 3), 6);
+            assert_eq!(get_next_ticket_id(&env), 9);
+
+            // Single purchases keep using the same counter
+            increment_ticket_id(&env);
+            assert_eq!(reserve_ticket_ids(&env, 2), 10);
+            assert_eq!(get_next_ticket_id(&env), 12);
+        });
+    }
+
+    #[test]
+    fn test_indexes_follow_creation_transfer_and_refund() {
+        let env = Env::default();
+        let contract_id = env.register(StorageHost, ());
+
+        env.as_contract(&contract_id, || {
+            let token = Address::generate(&env);
+            let organizer_event = event(&env);
+            let organizer = organizer_event.organizer.clone();
+            set_event(&env, 1, &organizer_event);
+            set_event(&env, 2, &organizer_event);
+            set_event(&env, 2, &organizer_event);
+
+            let mut first = ticket(&env, 1, 1, &token);
+            let second = ticket(&env, 2, 1, &token);
+            let alice = first.owner.clone();
+            let bob = second.owner.clone();
+            set_ticket(&env, 1, &first);
+            set_ticket(&env, 2, &second);
+
+            assert_eq!(
+                get_organizer_events(&env, &organizer, 0, 10),
+                Vec::from_array(&env, [1u64, 2])
+            );
+            assert_eq!(
+                get_event_tickets(&env, 1, 0, 10),
+                Vec::from_array(&env, [1u64, 2])
+            );
+            assert_eq!(
+                get_owner_tickets(&env, &alice, 0, 10),
+                Vec::from_array(&env, [1u64])
+            );
+
+            // Transfer moves the ticket between owners
+            first.owner = bob.clone();
+            set_ticket(&env, 1, &first);
+            assert!(get_owner_tickets(&env, &alice, 0, 10).is_empty());
+            assert_eq!(get_owner_ticket_positions(&env, &alice), 1);
+            assert_eq!(
+                get_owner_tickets(&env, &bob, 0, 10),
+                Vec::from_array(&env, [2u64, 1])
+            );
+
+            // Refunded tickets are no longer owned but stay listed under the event
+            first.refunded = true;
+            set_ticket(&env, 1, &first);
+            assert_eq!(
+                get_owner_tickets(&env, &bob, 0, 10),
+                Vec::from_array(&env, [2u64])
+            );
+            assert_eq!(get_event_tickets(&env, 1, 0, 10).len(), 2);
+        });
+    }
+
+    #[test]
+    fn test_batch_purchase_fits_write_budget() {
+        let env = Env::default();
+        let contract_id = env.register(StorageHost, ());
+        let token = Address::generate(&env);
+        let buyer = Address::generate(&env);
+
+        // Everything a batch purchase writes, in one invocation
+        env.as_contract(&contract_id, || {
+            let mut event = event(&env);
+            let first_id = reserve_ticket_ids(&env, MAX_BATCH_PURCHASE);
+            for id in first_id..first_id + MAX_BATCH_PURCHASE as u64 {
+                let mut ticket = ticket(&env, id, 1, &token);
+                ticket.owner = buyer.clone();
+                set_ticket(&env, id, &ticket);
+            }
+            let mut tier = event.tiers.get(0).unwrap();
+            tier.tickets_sold += MAX_BATCH_PURCHASE;
+            event.tiers.set(0, tier);
+            set_event(&env, 1, &event);
+            add_escrow(&env, 1, 0, &token, 900);
+            add_platform_balance(&env, 1, &token, 100);
+        });
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(
+                get_owner_tickets(&env, &buyer, 0, 10).len(),
+                MAX_BATCH_PURCHASE
+            );
+        });
+    }
+
+    #[test]
+    fn test_index_stores_one_entry_per_id() {
+        let env = Env::default();
+        let contract_id = env.register(StorageHost, ());
+
+        env.as_contract(&contract_id, || {
+            let key = (EVENT_TICKETS_PREFIX, 1u64);
+            for id in [1u64, 3, 4, 7, 9, 4] {
+                index_insert(&env, &key, id);
+            }
+            assert_eq!(index_len(&env, &key), 5);
+            assert_eq!(
+                env.storage().persistent().get(&(key, INDEX_ITEM, 3u32)),
+                Some(7u64)
+            );
+
+            assert_eq!(paginate(&env, &key, 0, 2), Vec::from_array(&env, [1u64, 3]));
+            assert_eq!(paginate(&env, &key, 2, 2), Vec::from_array(&env, [4u64, 7]));
+            assert!(paginate(&env, &key, 5, 10).is_empty());
+            assert!(paginate(&env, &key, 0, 0).is_empty());
+
+            // Removal leaves the position empty and later IDs where they were
+            let second_page = paginate(&env, &key, 2, 2);
+            index_remove(&env, &key, 3);
+            index_remove(&env, &key, 3);
+            assert_eq!(paginate(&env, &key, 0, 2), Vec::from_array(&env, [1u64]));
+            assert_eq!(paginate(&env, &key, 2, 2), second_page);
+            assert_eq!(
+                paginate(&env, &key, 0, 10),
+                Vec::from_array(&env, [1u64, 4, 7, 9])
+            );
+            for id in [1u64, 9, 4, 7] {
+                index_remove(&env, &key, id);
+            }
+            assert_eq!(index_len(&env, &key), 5);
+            assert!(paginate(&env, &key, 0, 10).is_empty());
+            assert!(!env.storage().persistent().has(&(key, INDEX_ITEM, 0u32)));
+
+            // A re-added ID takes a new position
+            index_insert(&env, &key, 3);
+            assert_eq!(index_len(&env, &key), 6);
+            assert_eq!(paginate(&env, &key, 5, 1), Vec::from_array(&env, [3u64]));
+        });
+
+        // Pages stay within the per-invocation entry limits however long the index is
+        let key = (EVENT_TICKETS_PREFIX, 2u64);
+        for id in 1..=(MAX_PAGE_SIZE as u64 + 5) {
+            env.as_contract(&contract_id, || index_insert(&env, &key, id));
+        }
+        env.as_contract(&contract_id, || {
+            assert_eq!(paginate(&env, &key, 0, u32::MAX).len(), MAX_PAGE_SIZE);
+            assert_eq!(paginate(&env, &key, MAX_PAGE_SIZE, u32::MAX).len(), 5);
+        });
+    }
+
+    #[test]
+    fn test_effective_fee_resolution_order() {
+        let env = Env::default();
+        let contract_id = env.register(StorageHost, ());
+
+        env.as_contract(&contract_id, || {
+            let event = event(&env);
+            set_platform_fee_bps(&env, 500);
+            assert_eq!(get_effective_fee_bps(&env, &event), 500);
+
+            assert_eq!(set_organizer_fee_bps(&env, &event.organizer, 200), Ok(()));
+            assert_eq!(get_effective_fee_bps(&env, &event), 200);
+
+            // A zero event override (charity) wins over the organizer rate
+            assert_eq!(set_event_fee_bps(&env, event.id, 0), Ok(()));
+            assert_eq!(get_effective_fee_bps(&env, &event), 0);
+
+            assert_eq!(remove_event_fee_bps(&env, event.id), Ok(()));
+            assert_eq!(get_effective_fee_bps(&env, &event), 200);
+            assert_eq!(remove_organizer_fee_bps(&env, &event.organizer), Ok(()));
+            assert_eq!(get_effective_fee_bps(&env, &event), 500);
+
+            // Overrides above 100% are rejected and leave the rate unchanged
+            assert_eq!(
+                set_organizer_fee_bps(&env, &event.organizer, 10001),
+                Err(LumentixError::InvalidBasisPoints)
+            );
+            assert_eq!(
+                set_event_fee_bps(&env, event.id, 10001),
+                Err(LumentixError::InvalidBasisPoints)
+            );
+            assert_eq!(get_effective_fee_bps(&env, &event), 500);
+        });
+    }
+
+    #[test]
+    fn test_fee_overrides_need_approvals_in_multi_admin_mode() {
+        let env = Env::default();
+        let contract_id = env.register(StorageHost, ());
+
+        env.as_contract(&contract_id, || {
+            let (a, b, c) = (
+                Address::generate(&env),
+                Address::generate(&env),
+                Address::generate(&env),
+            );
+            set_admin(&env, &a);
+            set_admins(&env, &Vec::from_array(&env, [a.clone(), b.clone(), c]), 2);
+            let event = event(&env);
+            let organizer = event.organizer.clone();
+            set_platform_fee_bps(&env, 500);
+
+            // One approval of a 2-of-3 set is not enough
+            let set_organizer_fee = AdminAction::SetOrganizerFee(organizer.clone(), 200);
+            add_approval(&env, &set_organizer_fee, &a).unwrap();
+            assert_eq!(
+                set_organizer_fee_bps(&env, &organizer, 200),
+                Err(LumentixError::ThresholdNotMet)
+            );
+            // Approvals cover the exact rate
+            add_approval(
+                &env,
+                &AdminAction::SetOrganizerFee(organizer.clone(), 100),
+                &b,
+            )
+            .unwrap();
+            assert_eq!(
+                set_organizer_fee_bps(&env, &organizer, 200),
+                Err(LumentixError::ThresholdNotMet)
+            );
+            add_approval(&env, &set_organizer_fee, &b).unwrap();
+            assert_eq!(set_organizer_fee_bps(&env, &organizer, 200), Ok(()));
+            assert!(get_approvals(&env, &set_organizer_fee).is_empty());
+
+            let set_event_fee = AdminAction::SetEventFee(event.id, 0);
+            assert_eq!(
+                set_event_fee_bps(&env, event.id, 0),
+                Err(LumentixError::ThresholdNotMet)
+            );
+            add_approval(&env, &set_event_fee, &a).unwrap();
+            add_approval(&env, &set_event_fee, &b).unwrap();
+            assert_eq!(set_event_fee_bps(&env, event.id, 0), Ok(()));
+            assert_eq!(get_effective_fee_bps(&env, &event), 0);
+
+            // Removals are gated too
+            assert_eq!(
+                remove_event_fee_bps(&env, event.id),
+                Err(LumentixError::ThresholdNotMet)
+            );
+            assert_eq!(
+                remove_organizer_fee_bps(&env, &organizer),
+                Err(LumentixError::ThresholdNotMet)
+            );
+            for admin in [&a, &b] {
+                add_approval(&env, &AdminAction::RemoveEventFee(event.id), admin).unwrap();
+                add_approval(
+                    &env,
+                    &AdminAction::RemoveOrganizerFee(organizer.clone()),
+                    admin,
+                )
+                .unwrap();
+            }
+            assert_eq!(remove_event_fee_bps(&env, event.id), Ok(()));
+            assert_eq!(get_effective_fee_bps(&env, &event), 200);
+            assert_eq!(remove_organizer_fee_bps(&env, &organizer), Ok(()));
+            assert_eq!(get_effective_fee_bps(&env, &event), 500);
+        });
+    }
+
+    #[test]
+    fn test_bump_event_extends_event_entries_and_pages_of_tickets() {
+        let env = Env::default();
+        let contract_id = env.register(StorageHost, ());
+        let token = Address::generate(&env);
+        let ticket_count = MAX_BUMP_PAGE_SIZE as u64 + 2;
+
+        env.as_contract(&contract_id, || {
+            set_token(&env, &token);
+            set_event(&env, 1, &event(&env));
+            set_event(&env, 2, &event(&env));
+            add_escrow(&env, 1, 0, &token, 200);
+            add_platform_balance(&env, 1, &token, 10);
+            join_waitlist(&env, 1, &Address::generate(&env)).unwrap();
+        });
+        // Event 1 gets more tickets than one page; ticket 1 belongs to event 2
+        for ticket_id in 1..=ticket_count + 1 {
+            let event_id = if ticket_id == 1 { 2 } else { 1 };
+            env.as_contract(&contract_id, || {
+                set_ticket(&env, ticket_id, &ticket(&env, ticket_id, event_id, &token));
+                increment_ticket_id(&env);
+            });
+        }
+        env.as_contract(&contract_id, || {
+            set_resale_listing(
+                &env,
+                &ResaleListing {
+                    ticket_id: 2,
+                    seller: Address::generate(&env),
+                    price: 120,
+                    listed_at: 0,
+                },
+            )
+        });
+
+        jump(&env, 25 * DAY_IN_LEDGERS);
+        let bump = |cursor| env.as_contract(&contract_id, || bump_event(&env, 1, cursor, u32::MAX));
+        assert_eq!(bump(0), Ok(MAX_BUMP_PAGE_SIZE));
+        assert_eq!(bump(MAX_BUMP_PAGE_SIZE), Ok(2));
+        assert_eq!(bump(MAX_BUMP_PAGE_SIZE + 2), Ok(0));
+
+        env.as_contract(&contract_id, || {
+            let persistent = env.storage().persistent();
+            for ticket_id in 2..=ticket_count + 1 {
+                assert_eq!(
+                    persistent.get_ttl(&(TICKET_PREFIX, ticket_id)),
+                    DEFAULT_TTL_EXTEND_TO
+                );
+            }
+            assert_eq!(
+                persistent.get_ttl(&(RESALE_LISTING_PREFIX, 2u64)),
+                DEFAULT_TTL_EXTEND_TO
+            );
+            for key in [
+                (ESCROW_PREFIX, 1u64, token.clone()),
+                (EVENT_FEES_PREFIX, 1u64, token.clone()),
+            ] {
+                assert_eq!(persistent.get_ttl(&key), DEFAULT_TTL_EXTEND_TO);
+            }
+            assert_eq!(
+                persistent.get_ttl(&(WAITLIST_PREFIX, 1u64)),
+                DEFAULT_TTL_EXTEND_TO
+            );
+            // Tickets of other events are left alone
+            assert_eq!(
+                persistent.get_ttl(&(TICKET_PREFIX, 1u64)),
+                5 * DAY_IN_LEDGERS
+            );
+
+            assert_eq!(
+                bump_event(&env, 99, 0, 10),
+                Err(LumentixError::EventNotFound)
+            );
+        });
+    }
+
+    #[test]
+    fn test_advance_draw_repay_and_cancelled_shortfall() {
+        let env = Env::default();
+        let contract_id = env.register(StorageHost, ());
+        let token = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            add_escrow(&env, 1, 0, &token, 1000);
+
+            assert_eq!(take_advance(&env, 1, &token, 300), Ok(300));
+            assert_eq!(get_escrow(&env, 1, &token), Ok(700));
+            assert_eq!(get_advanced(&env, 1, &token), 300);
+            assert_eq!(
+                take_advance(&env, 1, &token, 701),
+                Err(LumentixError::InsufficientEscrow)
+            );
+
+            // Only what was advanced can be repaid
+            assert_eq!(
+                repay_advance(&env, 1, &token, 301),
+                Err(LumentixError::InvalidAmount)
+            );
+            assert_eq!(repay_advance(&env, 1, &token, 100), Ok(200));
+            assert_eq!(get_escrow(&env, 1, &token), Ok(800));
+            assert_eq!(get_advanced(&env, 1, &token), 200);
+
+            // Refunds of a cancelled event are short by whatever is still advanced
+            let mut cancelled = event(&env);
+            cancelled.status = EventStatus::Cancelled;
+            let advanced = get_advanced(&env, 1, &token);
+            assert_eq!(calculate_refund_shortfall(&cancelled, advanced), 200);
+            assert_eq!(calculate_refund_shortfall(&event(&env), advanced), 0);
+
+            assert_eq!(repay_advance(&env, 1, &token, 200), Ok(0));
+            assert_eq!(get_escrow(&env, 1, &token), Ok(1000));
+            assert_eq!(
+                calculate_refund_shortfall(&cancelled, get_advanced(&env, 1, &token)),
+                0
+            );
+        });
+    }
+
+    #[test]
+    fn test_full_refund_ruling_needs_advances_repaid() {
+        let env = Env::default();
+        let contract_id = env.register(StorageHost, ());
+        let token = Address::generate(&env);
+        let ruling = DisputeResolution::FullRefund;
+
+        env.as_contract(&contract_id, || {
+            add_escrow(&env, 1, 0, &token, 1000);
+            take_advance(&env, 1, &token, 400).unwrap();
+
+            let escrow = get_escrow(&env, 1, &token).unwrap();
+            let advanced = get_advanced(&env, 1, &token);
+            assert_eq!(
+                calculate_dispute_release(escrow, advanced, &ruling),
+                Err(LumentixError::RefundUnderfunded)
+            );
+
+            // Repaying the shortfall funds every refund and releases nothing
+            let shortfall = calculate_dispute_shortfall(escrow, advanced, &ruling);
+            assert_eq!(repay_advance(&env, 1, &token, shortfall), Ok(0));
+            let escrow = get_escrow(&env, 1, &token).unwrap();
+            assert_eq!(calculate_dispute_release(escrow, 0, &ruling), Ok(0));
+            assert_eq!(deduct_escrow(&env, 1, 0, &token, 1000), Ok(()));
+        });
+    }
+
+    #[test]
+    fn test_platform_balance_through_purchase_refund_settle_and_withdraw() {
+        let env = Env::default();
+        let contract_id = env.register(StorageHost, ());
+
+        env.as_contract(&contract_id, || {
+            let token = Address::generate(&env);
+            let assert_non_negative = |event_ids: &[u64]| {
+                assert!(get_platform_balance(&env, &token) >= 0);
+                assert!(get_pending_fees(&env, &token) >= 0);
+                assert!(get_withdrawable_platform_balance(&env, &token) >= 0);
+                for event_id in event_ids {
+                    assert!(get_event_fees(&env, *event_id, &token) >= 0);
+                }
+            };
+
+            // Purchases: fees are in the balance but still pending
+            add_platform_balance(&env, 1, &token, 5);
+            add_platform_balance(&env, 1, &token, 5);
+            add_platform_balance(&env, 2, &token, 8);
+            assert_eq!(get_platform_balance(&env, &token), 18);
+            assert_eq!(get_event_fees(&env, 1, &token), 10);
+            assert_eq!(get_withdrawable_platform_balance(&env, &token), 0);
+            assert_non_negative(&[1, 2]);
+
+            // Event 1 is cancelled: one refund reverses its fee, and nothing
+            // beyond what the event collected can be clawed back
+            assert_eq!(reverse_platform_fee(&env, 1, &token, 5), Ok(()));
+            assert_eq!(
+                reverse_platform_fee(&env, 1, &token, 6),
+                Err(LumentixError::InsufficientPlatformBalance)
+            );
+            assert_eq!(get_event_fees(&env, 1, &token), 5);
+            assert_eq!(get_platform_balance(&env, &token), 13);
+            assert_non_negative(&[1, 2]);
+
+            // Event 2 completes: its fees become withdrawable
+            settle_event_fees(&env, 2, &token);
+            assert_eq!(get_event_fees(&env, 2, &token), 0);
+            assert_eq!(get_withdrawable_platform_balance(&env, &token), 8);
+            assert_eq!(
+                reverse_platform_fee(&env, 2, &token, 1),
+                Err(LumentixError::InsufficientPlatformBalance)
+            );
+            assert_non_negative(&[1, 2]);
+
+            // Withdrawal keeps the pending fees of event 1 covered
+            clear_platform_balance(&env, &token);
+            assert_eq!(get_platform_balance(&env, &token), 5);
+            assert_eq!(get_withdrawable_platform_balance(&env, &token), 0);
+            assert_non_negative(&[1, 2]);
+
+            // The last refund of event 1 still succeeds after the withdrawal
+            assert_eq!(reverse_platform_fee(&env, 1, &token, 5), Ok(()));
+            assert_eq!(get_platform_balance(&env, &token), 0);
+            assert_eq!(get_pending_fees(&env, &token), 0);
+            assert_non_negative(&[1, 2]);
+        });
+    }
+
+    #[test]
+    fn test_clearing_rebate_claim_reprices_ticket() {
+        let env = Env::default();
+        let contract_id = env.register(StorageHost, ());
+        let token = Address::generate(&env);
+
+        // Ticket 1 bought at 200 and ticket 2 at 120, both with a 10% fee
+        for (ticket_id, price_paid) in [(1u64, 200i128), (2, 120)] {
+            env.as_contract(&contract_id, || {
+                let mut bought = ticket(&env, ticket_id, 1, &token);
+                bought.price_paid = price_paid;
+                bought.platform_fee = price_paid / 10;
+                set_ticket(&env, ticket_id, &bought);
+                add_escrow(&env, 1, 0, &token, price_paid - bought.platform_fee);
+                add_platform_balance(&env, 1, &token, bought.platform_fee);
+            });
+        }
+        env.as_contract(&contract_id, || {
+            set_event(&env, 1, &event(&env));
+            // Falls from 200 to the floor of 100 by time 1000
+            set_auction(
+                &env,
+                1,
+                &DutchAuction {
+                    start_price: 200,
+                    floor_price: 100,
+                    start_time: 0,
+                    decay_period: 100,
+                    price_step: 10,
+                    uniform_clearing: true,
+                },
+            );
+
+            // The clearing price is the lowest price paid
+            assert_eq!(get_clearing_price(&env, 1), None);
+            for price in [200, 120, 150] {
+                record_auction_price(&env, 1, price);
+            }
+            assert_eq!(get_clearing_price(&env, 1), Some(120));
+        });
+
+        let claim = |ticket_id, now| {
+            env.as_contract(&contract_id, || claim_clearing_rebate(&env, ticket_id, now))
+        };
+        assert_eq!(claim(1, 999), Err(LumentixError::AuctionNotEnded));
+
+        // 80 back: 72 from escrow and 8 of the fee taken on the rebated amount
+        assert_eq!(claim(1, 1000), Ok(80));
+        assert_eq!(claim(1, 1000), Err(LumentixError::RebateAlreadyClaimed));
+        assert_eq!(claim(2, 1000), Ok(0));
+
+        env.as_contract(&contract_id, || {
+            let repriced = get_ticket(&env, 1).unwrap();
+            assert_eq!((repriced.price_paid, repriced.platform_fee), (120, 12));
+            assert!(is_rebate_claimed(&env, 1));
+            assert!(is_rebate_claimed(&env, 2));
+
+            // Both tickets now hold exactly their net price at clearing
+            assert_eq!(get_escrow(&env, 1, &token), Ok(216));
+            assert_eq!(get_tier_escrow(&env, 1, 0, &token), 216);
+            assert_eq!(get_event_fees(&env, 1, &token), 24);
+            assert_eq!(get_platform_balance(&env, &token), 24);
+        });
+    }
+
+    #[test]
+    fn test_postponement_round_trip_and_not_found() {
+        let env = Env::default();
+        let contract_id = env.register(StorageHost, ());
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(
+                get_postponement(&env, 1),
+                Err(LumentixError::PostponementNotFound)
+            );
+
+            let postponement = Postponement {
+                event_id: 1,
+                original_start_time: 1000,
+                original_end_time: 2000,
+                opt_out_deadline: 1500,
+            };
+            set_postponement(&env, &postponement);
+            assert_eq!(get_postponement(&env, 1), Ok(postponement));
+        });
+    }
+
+    #[test]
+    fn test_waitlist_is_fifo_and_bounded() {
+        let env = Env::default();
+        let contract_id = env.register(StorageHost, ());
+
+        env.as_contract(&contract_id, || {
+            let first = Address::generate(&env);
+            let second = Address::generate(&env);
+            let third = Address::generate(&env);
+
+            assert_eq!(join_waitlist(&env, 1, &first), Ok(1));
+            assert_eq!(join_waitlist(&env, 1, &second), Ok(2));
+            assert_eq!(join_waitlist(&env, 1, &third), Ok(3));
+            assert_eq!(
+                join_waitlist(&env, 1, &second),
+                Err(LumentixError::AlreadyOnWaitlist)
+            );
+
+            // Leaving keeps the order of everyone behind
+            assert_eq!(leave_waitlist(&env, 1, &second), Ok(()));
+            assert_eq!(
+                leave_waitlist(&env, 1, &second),
+                Err(LumentixError::NotOnWaitlist)
+            );
+            assert_eq!(pop_waitlist(&env, 1), Some(first));
+            assert_eq!(pop_waitlist(&env, 1), Some(third));
+            assert_eq!(pop_waitlist(&env, 1), None);
+
+            // Waitlists are per event
+            assert!(get_waitlist(&env, 2).is_empty());
+
+            for _ in 0..MAX_WAITLIST_SIZE {
+                join_waitlist(&env, 2, &Address::generate(&env)).unwrap();
+            }
+            assert_eq!(
+                join_waitlist(&env, 2, &Address::generate(&env)),
+                Err(LumentixError::WaitlistFull)
+            );
+        });
+    }
+
+    #[test]
+    fn test_grant_revoke_and_require_role() {
+        let env = Env::default();
+        let contract_id = env.register(StorageHost, ());
+
+        env.as_contract(&contract_id, || {
+            let event = event(&env);
+            let staff = Address::generate(&env);
+            let stranger = Address::generate(&env);
+
+            // The organizer passes every role check without a grant
+            assert_eq!(
+                require_role(&env, &event, &event.organizer, EventRole::Finance),
+                Ok(())
+            );
+            assert_eq!(
+                require_role(&env, &event, &staff, EventRole::GateStaff),
+                Err(LumentixError::Unauthorized)
+            );
+
+            grant_role(&env, event.id, &staff, EventRole::GateStaff);
+            grant_role(&env, event.id, &staff, EventRole::GateStaff);
+            grant_role(&env, event.id, &staff, EventRole::Manager);
+            assert_eq!(get_roles(&env, event.id, &staff).len(), 2);
+            assert_eq!(
+                require_role(&env, &event, &staff, EventRole::GateStaff),
+                Ok(())
+            );
+            assert_eq!(
+                require_role(&env, &event, &staff, EventRole::Finance),
+                Err(LumentixError::Unauthorized)
+            );
+            // Roles are per event
+            assert!(!has_role(&env, 2, &staff, EventRole::GateStaff));
+            assert!(!has_role(&env, event.id, &stranger, EventRole::GateStaff));
+
+            revoke_role(&env, event.id, &staff, EventRole::GateStaff);
+            assert_eq!(
+                require_role(&env, &event, &staff, EventRole::GateStaff),
+                Err(LumentixError::Unauthorized)
+            );
+            assert!(has_role(&env, event.id, &staff, EventRole::Manager));
+
+            // Revoking the last role removes the entry
+            revoke_role(&env, event.id, &staff, EventRole::Manager);
+            assert!(!env
+                .storage()
+                .persistent()
+                .has(&(ROLE_PREFIX, event.id, staff.clone())));
+        });
+    }
+
+    #[test]
+    fn test_approvals_only_count_current_admins() {
+        let env = Env::default();
+        let contract_id = env.register(StorageHost, ());
+
+        env.as_contract(&contract_id, || {
+            let (a, b, c) = (
+                Address::generate(&env),
+                Address::generate(&env),
+                Address::generate(&env),
+            );
+            set_admin(&env, &a);
+            set_admins(&env, &Vec::from_array(&env, [a.clone(), b.clone()]), 2);
+            let action = AdminAction::SetPlatformFee(300);
+
+            assert_eq!(
+                add_approval(&env, &action, &c),
+                Err(LumentixError::Unauthorized)
+            );
+            assert_eq!(add_approval(&env, &action, &b), Ok(1));
+            assert_eq!(
+                add_approval(&env, &action, &b),
+                Err(LumentixError::AlreadyApproved)
+            );
+
+            // b is rotated out: its approval no longer counts toward the threshold
+            set_admins(&env, &Vec::from_array(&env, [a.clone(), c.clone()]), 2);
+            assert!(get_approvals(&env, &action).is_empty());
+            assert_eq!(add_approval(&env, &action, &a), Ok(1));
+            assert_eq!(add_approval(&env, &action, &c), Ok(2));
+
+            clear_approvals(&env, &action);
+            assert!(get_approvals(&env, &action).is_empty());
+
+            // Withdrawal approvals cover one token only
+            let usdc = Address::generate(&env);
+            let eurc = Address::generate(&env);
+            let withdraw_usdc = AdminAction::WithdrawPlatformFees(usdc, a.clone());
+            assert_eq!(add_approval(&env, &withdraw_usdc, &a), Ok(1));
+            assert_eq!(add_approval(&env, &withdraw_usdc, &c), Ok(2));
+            assert!(get_approvals(&env, &AdminAction::WithdrawPlatformFees(eurc, a)).is_empty());
+        });
+    }
+
+    #[test]
+    fn test_admin_handover_is_single_admin_only() {
+        let env = Env::default();
+        let contract_id = env.register(StorageHost, ());
+
+        env.as_contract(&contract_id, || {
+            let admin = Address::generate(&env);
+            let successor = Address::generate(&env);
+            set_admin(&env, &admin);
+            assert!(!is_multi_admin(&env));
+
+            assert_eq!(set_pending_admin(&env, &successor), Ok(()));
+            assert_eq!(get_pending_admin(&env), Ok(successor.clone()));
+
+            // Enabling multi-admin mode drops the pending handover and blocks new ones
+            set_admins(&env, &Vec::from_array(&env, [admin, successor.clone()]), 1);
+            assert!(is_multi_admin(&env));
+            assert_eq!(get_pending_admin(&env), Err(LumentixError::NoPendingAdmin));
+            assert_eq!(
+                set_pending_admin(&env, &successor),
+                Err(LumentixError::HandoverNotAllowed)
+            );
+        });
+    }
+
+    #[test]
+    fn test_refunds_still_work_while_purchases_are_paused() {
+        let env = Env::default();
+        let contract_id = env.register(StorageHost, ());
+
+        env.as_contract(&contract_id, || {
+            let token = Address::generate(&env);
+            add_escrow(&env, 1, 0, &token, 95);
+            add_platform_balance(&env, 1, &token, 5);
+
+            set_paused(&env, PauseScope::Purchases, true);
+            assert_eq!(
+                require_not_paused(&env, PauseScope::Purchases),
+                Err(LumentixError::ContractPaused)
+            );
+
+            // A cancellation refund only checks the Refunds scope
+            assert_eq!(require_not_paused(&env, PauseScope::Refunds), Ok(()));
+            assert_eq!(deduct_escrow(&env, 1, 0, &token, 95), Ok(()));
+            assert_eq!(reverse_platform_fee(&env, 1, &token, 5), Ok(()));
+            assert_eq!(get_escrow(&env, 1, &token), Ok(0));
+
+            set_paused(&env, PauseScope::Refunds, true);
+            assert_eq!(
+                require_not_paused(&env, PauseScope::Refunds),
+                Err(LumentixError::ContractPaused)
+            );
+            set_paused(&env, PauseScope::Purchases, false);
+            assert_eq!(require_not_paused(&env, PauseScope::Purchases), Ok(()));
+        });
+    }
 }
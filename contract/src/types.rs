@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, BytesN, String, Symbol, Vec};
 
 /// Event status enum mirroring backend statuses
 #[contracttype]
@@ -8,12 +8,307 @@ pub enum EventStatus {
     Published,
     Completed,
     Cancelled,
+    Postponed,
 }
 
-/// Event structure
+/// Ticket tier structure (e.g. General Admission, VIP, Student)
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TicketTier {
+    pub id: u32,
+    pub name: String,
+    pub price: i128,
+    pub max_tickets: u32,
+    pub tickets_sold: u32,
+}
+
+/// One step of an event's refund schedule: tickets refunded at least
+/// `cutoff_secs` before `start_time` get `refund_bps` of the price back
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefundRule {
+    pub cutoff_secs: u64,
+    pub refund_bps: u32,
+}
+
+/// Allowlist presale phase that runs before public sale opens
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PresaleConfig {
+    /// Merkle root over sha256(address XDR) leaves, paired with sorted sha256
+    pub merkle_root: BytesN<32>,
+    /// Maximum tickets each allowlisted address may buy during presale
+    pub per_address_limit: u32,
+    /// Ledger timestamp at which the event switches to public sale
+    pub public_sale_time: u64,
+}
+
+/// Dutch auction pricing: the price falls by `price_step` every
+/// `decay_period` seconds from `start_price` down to `floor_price`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DutchAuction {
+    pub start_price: i128,
+    pub floor_price: i128,
+    pub start_time: u64,
+    pub decay_period: u64,
+    pub price_step: i128,
+    /// When set, early buyers can reclaim the difference to the clearing price
+    pub uniform_clearing: bool,
+}
+
+/// Price bump applied once a share of capacity has been sold
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PriceThreshold {
+    /// Share of `max_tickets` sold, in basis points, at which the bump starts
+    pub fill_bps: u32,
+    /// Increase over the base price, in basis points
+    pub bump_bps: u32,
+}
+
+/// Dynamic pricing curve based on tickets sold
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PricingCurve {
+    /// Price rises by a fixed amount (second field) every N tickets sold (first field)
+    LinearSteps(u32, i128),
+    /// Price rises by a percentage at each fill threshold
+    Thresholds(Vec<PriceThreshold>),
+}
+
+/// Payment token accepted for a tier of an event and the ticket price of that
+/// tier in that token
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AcceptedToken {
+    pub token: Address,
+    pub tier_id: u32,
+    pub price: i128,
+}
+
+/// Admin-configured price oracle used for fiat-pegged ticket prices
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OracleConfig {
+    pub oracle: Address,
+    /// Maximum age of an oracle price in seconds
+    pub max_staleness: u64,
+    /// Maximum move from the last accepted price, in basis points
+    pub max_deviation_bps: u32,
+    /// How long an accepted price stays the deviation reference, in seconds
+    pub deviation_window: u64,
+}
+
+/// Price reported by the oracle: token units per one reference currency unit,
+/// scaled by the oracle's decimals
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+/// Operations the admin can pause independently
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PauseScope {
+    Purchases,
+    Refunds,
+    EscrowRelease,
+    CheckIn,
+}
+
+/// Storage TTL policy in ledgers: entries whose TTL drops below `threshold`
+/// are extended to `extend_to` whenever they are read or written
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlConfig {
+    pub threshold: u32,
+    pub extend_to: u32,
+}
+
+/// Admin-approved milestones at which an organizer may draw escrow before completion.
+/// Shares are cumulative fractions of the event's gross escrow (balance plus advances).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdvancePolicy {
+    /// Share unlocked once the event is Published
+    pub published_bps: u32,
+    /// Number of check-ins that unlocks `checkin_bps`
+    pub checkin_threshold: u32,
+    /// Additional share unlocked once `checkin_threshold` tickets are checked in
+    pub checkin_bps: u32,
+}
+
+/// Sensitive platform operation that needs M-of-N admin approval in multi-admin mode
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AdminAction {
+    SetPlatformFee(u32),
+    /// Withdraw the platform balance of a token (first) to a recipient (second)
+    WithdrawPlatformFees(Address, Address),
+    SetAdmins(Vec<Address>, u32),
+    SetAdvancePolicy(u64, AdvancePolicy),
+    /// Replace the contract code with the uploaded WASM of this hash
+    Upgrade(BytesN<32>),
+    /// Migrate stored records to this schema version
+    Migrate(u32),
+    /// Override the platform fee (bps) for all events of an organizer
+    SetOrganizerFee(Address, u32),
+    /// Remove an organizer's platform fee override
+    RemoveOrganizerFee(Address),
+    /// Override the platform fee (bps) for a single event
+    SetEventFee(u64, u32),
+    /// Remove an event's platform fee override
+    RemoveEventFee(u64),
+}
+
+/// Per-event staff roles granted by the organizer
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EventRole {
+    /// Edit and publish the event
+    Manager,
+    /// Release escrow and configure payouts
+    Finance,
+    /// Check tickets in at the gate (use_ticket)
+    GateStaff,
+}
+
+/// Event structure; prices, capacity and sales are tracked per tier
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Event {
+    pub id: u64,
+    pub organizer: Address,
+    pub name: String,
+    pub description: String,
+    pub location: String,
+    pub start_time: u64,
+    pub end_time: u64,
+    /// At least one tier; events created before tiers existed have the default tier
+    pub tiers: Vec<TicketTier>,
+    pub refund_schedule: Vec<RefundRule>,
+    pub accepted_tokens: Vec<AcceptedToken>,
+    /// Reference currency (e.g. USD) when tier prices are fiat-pegged
+    pub reference_currency: Option<Symbol>,
+    pub status: EventStatus,
+}
+
+/// Details of a postponed event: its original times and the window in
+/// which ticket holders may opt out for a full refund
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Postponement {
+    pub event_id: u64,
+    pub original_start_time: u64,
+    pub original_end_time: u64,
+    pub opt_out_deadline: u64,
+}
+
+/// Changes requested by `update_event`; `None` leaves a field unchanged
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventUpdate {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub location: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub ticket_price: Option<i128>,
+    pub max_tickets: Option<u32>,
+    /// Tier that `ticket_price` / `max_tickets` apply to (required when either is set)
+    pub tier_id: Option<u32>,
+}
+
+/// Ticket structure
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ticket {
+    pub id: u64,
+    pub event_id: u64,
+    pub tier_id: u32,
+    pub owner: Address,
+    pub payment_token: Address,
+    pub price_paid: i128,
+    pub platform_fee: i128,
+    pub purchase_time: u64,
+    pub used: bool,
+    pub refunded: bool,
+}
+
+/// A beneficiary of an event's revenue and its share in basis points
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Payee {
+    pub address: Address,
+    pub share_bps: u32,
+}
+
+/// How an event's escrow is split on release; rounding dust goes to `primary`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RevenueSplit {
+    pub payees: Vec<Payee>,
+    pub primary: Address,
+}
+
+/// Organizer-configured rules for secondary ticket sales
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResalePolicy {
+    /// Maximum resale price as basis points of face value (e.g. 11000 = 110%)
+    pub max_price_bps: u32,
+    /// Royalty paid to the organizer in basis points of the resale price
+    pub royalty_bps: u32,
+}
+
+/// Active resale listing for a ticket
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResaleListing {
+    pub ticket_id: u64,
+    pub seller: Address,
+    pub price: i128,
+    pub listed_at: u64,
+}
+
+/// Time-limited exclusive offer for the next waitlisted buyer
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WaitlistOffer {
+    pub event_id: u64,
+    pub buyer: Address,
+    pub price: i128,
+    pub expires_at: u64,
+}
+
+/// Discount applied by a promo code
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Discount {
+    /// Percentage off the ticket price in basis points
+    Percentage(u32),
+    /// Fixed amount off the ticket price
+    Fixed(i128),
+}
+
+/// Promo code registered by an organizer, stored by the sha256 hash of the code
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PromoCode {
+    pub code_hash: BytesN<32>,
+    pub discount: Discount,
+    pub max_redemptions: u32,
+    pub redemptions: u32,
+    pub expires_at: u64,
+}
+
+/// Event layout stored before schema version 2 (read only by `migrate`)
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventV1 {
     pub id: u64,
     pub organizer: Address,
     pub name: String,
@@ -27,10 +322,10 @@ pub struct Event {
     pub status: EventStatus,
 }
 
-/// Ticket structure
+/// Ticket layout stored before schema version 2 (read only by `migrate`)
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct Ticket {
+pub struct TicketV1 {
     pub id: u64,
     pub event_id: u64,
     pub owner: Address,
@@ -48,3 +343,36 @@ pub struct FeeCollectedEvent {
     pub platform_fee: i128,
     pub organizer_amount: i128,
 }
+
+/// Admin ruling on a post-event dispute
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DisputeResolution {
+    /// Escrow is released to the organizer in full
+    FullRelease,
+    /// Every holder is refunded this share (bps) of what was escrowed for their ticket
+    PartialRefund(u32),
+    /// Every holder is refunded everything escrowed for their ticket
+    FullRefund,
+}
+
+/// State of a dispute: open until the admin rules on it
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DisputeStatus {
+    Open,
+    Resolved(DisputeResolution),
+}
+
+/// Dispute opened by an attendee during an event's post-event dispute window
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Dispute {
+    pub event_id: u64,
+    pub opened_by: Address,
+    pub ticket_id: u64,
+    pub reason: String,
+    pub opened_at: u64,
+    pub status: DisputeStatus,
+    pub resolved_at: Option<u64>,
+}
@@ -0,0 +1,397 @@
+use crate::error::LumentixError;
+use crate::storage;
+use crate::types::{AdminAction, Event, EventV1, Ticket, TicketTier, TicketV1};
+use crate::validation;
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
+
+/// Schema version of the Event/Ticket layouts defined in `types.rs`
+/// Bump this and extend `migrate` whenever a stored layout changes
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+/// Tier that holds the tickets and escrow of events created before tiers existed
+pub const DEFAULT_TIER_ID: u32 = 0;
+
+/// Maximum number of records `migrate` converts in one invocation
+pub const MAX_MIGRATION_BATCH: u32 = 50;
+
+/// Replace the running contract code with an uploaded WASM (admin only)
+/// In multi-admin mode the hash needs an approved AdminAction::Upgrade.
+/// Stored data is untouched; call `migrate` afterwards if the schema version changed
+pub fn upgrade(env: &Env, admin: &Address, new_wasm_hash: BytesN<32>) -> Result<(), LumentixError> {
+    require_admin(env, admin)?;
+    let action = AdminAction::Upgrade(new_wasm_hash.clone());
+    storage::require_approved(env, &action)?;
+
+    storage::clear_approvals(env, &action);
+    env.deployer().update_current_contract_wasm(new_wasm_hash);
+    Ok(())
+}
+
+/// Rewrite up to `limit` stored records from the schema version they were
+/// written with to CURRENT_SCHEMA_VERSION (admin only), keeping escrow and
+/// platform balances intact. Progress is recorded between calls; returns true
+/// once every record is converted and the schema version is bumped.
+/// In multi-admin mode every batch needs an approved AdminAction::Migrate.
+pub fn migrate(env: &Env, admin: &Address, limit: u32) -> Result<bool, LumentixError> {
+    require_admin(env, admin)?;
+    if storage::get_schema_version(env) >= CURRENT_SCHEMA_VERSION {
+        return Err(LumentixError::AlreadyMigrated);
+    }
+    let action = AdminAction::Migrate(CURRENT_SCHEMA_VERSION);
+    storage::require_approved(env, &action)?;
+
+    if !migrate_from_v1(env, limit.clamp(1, MAX_MIGRATION_BATCH))? {
+        return Ok(false);
+    }
+    storage::clear_approvals(env, &action);
+    storage::clear_migration_cursor(env);
+    storage::set_schema_version(env, CURRENT_SCHEMA_VERSION);
+    Ok(true)
+}
+
+/// Fail with MigrationPending while stored records are older than
+/// CURRENT_SCHEMA_VERSION, since they cannot be decoded as the current layouts
+pub fn require_migrated(env: &Env) -> Result<(), LumentixError> {
+    if storage::get_schema_version(env) < CURRENT_SCHEMA_VERSION {
+        return Err(LumentixError::MigrationPending);
+    }
+    Ok(())
+}
+
+fn require_admin(env: &Env, admin: &Address) -> Result<(), LumentixError> {
+    admin.require_auth();
+    if !storage::is_admin(env, admin) {
+        return Err(LumentixError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Version 1: no tiers, single-token escrow and untracked fees
+/// The cursor walks every event ID, then every ticket ID (tickets need their
+/// event in the current layout), and the platform balance moves last.
+fn migrate_from_v1(env: &Env, limit: u32) -> Result<bool, LumentixError> {
+    let token = storage::get_token(env);
+    let next_event_id = storage::get_next_event_id(env);
+    let end = next_event_id + storage::get_next_ticket_id(env) - 1;
+
+    let mut position = storage::get_migration_cursor(env);
+    let mut migrated = 0;
+    while position < end && migrated < limit {
+        if position < next_event_id {
+            let event_id = position;
+            if let Some(legacy) = storage::take_legacy_event(env, event_id) {
+                storage::set_event(env, event_id, &migrate_event_v1(env, legacy));
+
+                let escrow = storage::take_legacy_escrow(env, event_id);
+                if escrow > 0 {
+                    storage::add_escrow(env, event_id, DEFAULT_TIER_ID, &token, escrow);
+                }
+            }
+        } else {
+            let ticket_id = position - next_event_id + 1;
+            if let Some(legacy) = storage::take_legacy_ticket(env, ticket_id) {
+                let event = storage::get_event(env, legacy.event_id)?;
+                // Tickets of an event are migrated in issue order, so the event's
+                // tickets migrated so far give this ticket's position
+                let position = storage::get_event_ticket_count(env, event.id);
+                let escrow = storage::get_tier_escrow(env, event.id, DEFAULT_TIER_ID, &token);
+                let ticket = migrate_ticket_v1(legacy, &event, &token, escrow, position)?;
+                storage::set_ticket(env, ticket_id, &ticket);
+            }
+        }
+        position += 1;
+        migrated += 1;
+    }
+
+    if position < end {
+        storage::set_migration_cursor(env, position);
+        return Ok(false);
+    }
+
+    let platform_balance = storage::take_legacy_platform_balance(env);
+    storage::credit_platform_balance(env, &token, platform_balance);
+    Ok(true)
+}
+
+/// Convert a version 1 event: its single price and capacity become the default tier
+fn migrate_event_v1(env: &Env, legacy: EventV1) -> Event {
+    let mut tiers = Vec::new(env);
+    tiers.push_back(TicketTier {
+        id: DEFAULT_TIER_ID,
+        name: String::from_str(env, "General Admission"),
+        price: legacy.ticket_price,
+        max_tickets: legacy.max_tickets,
+        tickets_sold: legacy.tickets_sold,
+    });
+
+    Event {
+        id: legacy.id,
+        organizer: legacy.organizer,
+        name: legacy.name,
+        description: legacy.description,
+        location: legacy.location,
+        start_time: legacy.start_time,
+        end_time: legacy.end_time,
+        tiers,
+        refund_schedule: Vec::new(env),
+        accepted_tokens: Vec::new(env),
+        reference_currency: None,
+        status: legacy.status,
+    }
+}
+
+/// Convert a version 1 ticket. Version 1 escrowed only the net amount of each
+/// sale without recording the fee, so each ticket is credited an equal share of
+/// the event's legacy escrow and the rest of its price counts as platform fee.
+/// The first `escrow % tickets_sold` tickets get one extra unit, so the shares
+/// add up to the whole escrow and refunding every ticket drains it exactly.
+fn migrate_ticket_v1(
+    legacy: TicketV1,
+    event: &Event,
+    token: &Address,
+    escrow: i128,
+    position: u32,
+) -> Result<Ticket, LumentixError> {
+    let (_, tier) = validation::find_tier(event, DEFAULT_TIER_ID)?;
+    let sold = tier.tickets_sold.max(1) as i128;
+    let mut share = escrow / sold;
+    if (position as i128) < escrow % sold {
+        share += 1;
+    }
+    let share = share.clamp(0, tier.price);
+
+    Ok(Ticket {
+        id: legacy.id,
+        event_id: legacy.event_id,
+        tier_id: DEFAULT_TIER_ID,
+        owner: legacy.owner,
+        payment_token: token.clone(),
+        price_paid: tier.price,
+        platform_fee: tier.price - share,
+        purchase_time: legacy.purchase_time,
+        used: legacy.used,
+        refunded: legacy.refunded,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{DisputeResolution, EventStatus};
+    use soroban_sdk::{contract, testutils::Address as _, vec};
+
+    #[contract]
+    struct MigrationHost;
+
+    fn write_v1_state(env: &Env, organizer: &Address, buyer: &Address) {
+        let event = EventV1 {
+            id: 1,
+            organizer: organizer.clone(),
+            name: String::from_str(env, "Test Event"),
+            description: String::from_str(env, "Description"),
+            location: String::from_str(env, "Location"),
+            start_time: 1000,
+            end_time: 2000,
+            ticket_price: 100,
+            max_tickets: 50,
+            tickets_sold: 2,
+            status: EventStatus::Published,
+        };
+        env.storage().persistent().set(&("EVENT_", 1u64), &event);
+        storage::increment_event_id(env);
+
+        for id in 1..=2u64 {
+            let ticket = TicketV1 {
+                id,
+                event_id: 1,
+                owner: buyer.clone(),
+                purchase_time: 500,
+                used: false,
+                refunded: false,
+            };
+            env.storage().persistent().set(&("TICKET_", id), &ticket);
+            storage::increment_ticket_id(env);
+        }
+
+        // 10% platform fee on two tickets at 100
+        env.storage().persistent().set(&("ESCROW_", 1u64), &180i128);
+        env.storage().instance().set(&"PLATFORM_BAL", &20i128);
+    }
+
+    #[test]
+    fn test_migrate_v1_in_batches_preserves_events_and_balances() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(MigrationHost, ());
+        let admin = Address::generate(&env);
+        let token = Address::generate(&env);
+        let organizer = Address::generate(&env);
+        let buyer = Address::generate(&env);
+        // Each call runs in its own frame, like separate transactions
+        let migrate_batch = |limit| env.as_contract(&contract_id, || migrate(&env, &admin, limit));
+
+        env.as_contract(&contract_id, || {
+            storage::set_admin(&env, &admin);
+            storage::set_token(&env, &token);
+            write_v1_state(&env, &organizer, &buyer);
+            assert_eq!(storage::get_schema_version(&env), 1);
+            assert_eq!(require_migrated(&env), Err(LumentixError::MigrationPending));
+        });
+
+        // One event and two tickets: the first batch converts the event and
+        // the first ticket, the second finishes the job
+        assert_eq!(migrate_batch(2), Ok(false));
+        env.as_contract(&contract_id, || {
+            assert_eq!(storage::get_schema_version(&env), 1);
+            assert_eq!(storage::get_migration_cursor(&env), 3);
+            assert_eq!(require_migrated(&env), Err(LumentixError::MigrationPending));
+            assert_eq!(storage::get_withdrawable_platform_balance(&env, &token), 0);
+        });
+
+        assert_eq!(migrate_batch(2), Ok(true));
+        env.as_contract(&contract_id, || {
+            assert_eq!(storage::get_schema_version(&env), CURRENT_SCHEMA_VERSION);
+            assert_eq!(require_migrated(&env), Ok(()));
+
+            let event = storage::get_event(&env, 1).unwrap();
+            assert_eq!(event.organizer, organizer);
+            assert_eq!(event.status, EventStatus::Published);
+            assert_eq!(event.tiers.len(), 1);
+            assert_eq!(event.tiers.get(0).unwrap().tickets_sold, 2);
+
+            let ticket = storage::get_ticket(&env, 2).unwrap();
+            assert_eq!(ticket.owner, buyer);
+            assert_eq!(ticket.tier_id, DEFAULT_TIER_ID);
+            assert_eq!(ticket.payment_token, token);
+            assert_eq!(ticket.price_paid, 100);
+            assert_eq!(ticket.platform_fee, 10);
+            assert_eq!(storage::get_owner_tickets(&env, &buyer, 0, 10).len(), 2);
+            assert_eq!(storage::get_event_tickets(&env, 1, 0, 10).len(), 2);
+
+            assert_eq!(storage::get_escrow(&env, 1, &token), Ok(180));
+            assert_eq!(
+                storage::get_tier_escrow(&env, 1, DEFAULT_TIER_ID, &token),
+                180
+            );
+            assert_eq!(storage::get_withdrawable_platform_balance(&env, &token), 20);
+        });
+
+        // Refunding every migrated ticket returns exactly the legacy escrow
+        env.as_contract(&contract_id, || {
+            for id in 1..=2u64 {
+                let ticket = storage::get_ticket(&env, id).unwrap();
+                let refund =
+                    validation::calculate_dispute_refund(&ticket, &DisputeResolution::FullRefund);
+                assert_eq!(refund, 90);
+                storage::deduct_escrow(&env, 1, DEFAULT_TIER_ID, &token, refund).unwrap();
+            }
+            assert_eq!(storage::get_escrow(&env, 1, &token), Ok(0));
+        });
+
+        assert_eq!(migrate_batch(2), Err(LumentixError::AlreadyMigrated));
+    }
+
+    #[test]
+    fn test_migrated_escrow_shares_spread_the_remainder() {
+        let env = Env::default();
+        let token = Address::generate(&env);
+        let buyer = Address::generate(&env);
+        let event = migrate_event_v1(
+            &env,
+            EventV1 {
+                id: 1,
+                organizer: Address::generate(&env),
+                name: String::from_str(&env, "Test Event"),
+                description: String::from_str(&env, "Description"),
+                location: String::from_str(&env, "Location"),
+                start_time: 1000,
+                end_time: 2000,
+                ticket_price: 100,
+                max_tickets: 50,
+                tickets_sold: 3,
+                status: EventStatus::Published,
+            },
+        );
+
+        // 271 escrowed over three tickets: shares of 91, 90 and 90
+        let mut escrowed = 0;
+        for (position, fee) in [(0u32, 9i128), (1, 10), (2, 10)] {
+            let legacy = TicketV1 {
+                id: position as u64 + 1,
+                event_id: 1,
+                owner: buyer.clone(),
+                purchase_time: 500,
+                used: false,
+                refunded: false,
+            };
+            let ticket = migrate_ticket_v1(legacy, &event, &token, 271, position).unwrap();
+            assert_eq!(ticket.platform_fee, fee);
+            escrowed += ticket.price_paid - ticket.platform_fee;
+        }
+        assert_eq!(escrowed, 271);
+    }
+
+    #[test]
+    fn test_upgrade_and_migrate_require_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(MigrationHost, ());
+        let admin = Address::generate(&env);
+        let stranger = Address::generate(&env);
+
+        env.as_contract(&contract_id, || storage::set_admin(&env, &admin));
+        assert_eq!(
+            env.as_contract(&contract_id, || {
+                upgrade(&env, &stranger, BytesN::from_array(&env, &[0; 32]))
+            }),
+            Err(LumentixError::Unauthorized)
+        );
+        assert_eq!(
+            env.as_contract(&contract_id, || migrate(&env, &stranger, 10)),
+            Err(LumentixError::Unauthorized)
+        );
+    }
+
+    #[test]
+    fn test_upgrade_and_migrate_need_approvals_in_multi_admin_mode() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(MigrationHost, ());
+        let (a, b, c) = (
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        );
+        let hash = BytesN::from_array(&env, &[7; 32]);
+        let migration = AdminAction::Migrate(CURRENT_SCHEMA_VERSION);
+
+        env.as_contract(&contract_id, || {
+            storage::set_admin(&env, &a);
+            storage::set_token(&env, &Address::generate(&env));
+            storage::set_admins(&env, &vec![&env, a.clone(), b.clone(), c.clone()], 2);
+            storage::add_approval(&env, &AdminAction::Upgrade(hash.clone()), &a).unwrap();
+            storage::add_approval(&env, &migration, &a).unwrap();
+        });
+
+        // One approval of a 2-of-3 set is not enough
+        assert_eq!(
+            env.as_contract(&contract_id, || upgrade(&env, &a, hash.clone())),
+            Err(LumentixError::ThresholdNotMet)
+        );
+        assert_eq!(
+            env.as_contract(&contract_id, || migrate(&env, &a, 10)),
+            Err(LumentixError::ThresholdNotMet)
+        );
+
+        env.as_contract(&contract_id, || {
+            storage::add_approval(&env, &migration, &b).unwrap();
+        });
+        assert_eq!(
+            env.as_contract(&contract_id, || migrate(&env, &a, 10)),
+            Ok(true)
+        );
+        env.as_contract(&contract_id, || {
+            assert_eq!(storage::get_approvals(&env, &migration).len(), 0);
+        });
+    }
+}
@@ -1,5 +1,11 @@
-use soroban_sdk::{Address, String};
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, String, Vec};
 use crate::error::LumentixError;
+use crate::types::{
+    AcceptedToken, AdvancePolicy, Discount, Dispute, DisputeResolution, DisputeStatus,
+    DutchAuction, Event, EventStatus, EventUpdate, Postponement, PresaleConfig, PricingCurve,
+    PromoCode, RefundRule, ResalePolicy, RevenueSplit, Ticket, TicketTier, TtlConfig,
+    WaitlistOffer,
+};
 
 /// Validate that an address is not invalid
 /// In Soroban, addresses are validated by the SDK, but we keep this for consistency
@@ -25,6 +31,874 @@ pub fn validate_positive_capacity(capacity: u32) -> Result<(), LumentixError> {
     Ok(())
 }
 
+/// Maximum number of tickets that can be bought in a single batch purchase
+/// Each ticket adds about ten entries to the footprint (the ticket and its event
+/// and owner index entries, with their TTL reads) on top of about twenty for the
+/// event, escrow, fee balances and counters. Seven tickets measure 91 entries,
+/// within the 100 an invocation may touch; eight exceed it.
+pub const MAX_BATCH_PURCHASE: u32 = 7;
+
+/// Validate the quantity of a batch purchase (between 1 and MAX_BATCH_PURCHASE)
+pub fn validate_purchase_quantity(quantity: u32) -> Result<(), LumentixError> {
+    if quantity == 0 || quantity > MAX_BATCH_PURCHASE {
+        return Err(LumentixError::InvalidQuantity);
+    }
+    Ok(())
+}
+
+/// Calculate the total price of `quantity` tickets, rejecting overflow
+pub fn calculate_total_price(price: i128, quantity: u32) -> Result<i128, LumentixError> {
+    price
+        .checked_mul(quantity as i128)
+        .ok_or(LumentixError::InvalidAmount)
+}
+
+/// Validate a multi-admin configuration: 1 <= threshold <= admins, no duplicates
+pub fn validate_admin_set(admins: &Vec<Address>, threshold: u32) -> Result<(), LumentixError> {
+    if threshold == 0 || threshold > admins.len() {
+        return Err(LumentixError::InvalidThreshold);
+    }
+    for (i, admin) in admins.iter().enumerate() {
+        if admins.iter().skip(i + 1).any(|other| other == admin) {
+            return Err(LumentixError::InvalidThreshold);
+        }
+    }
+    Ok(())
+}
+
+/// Validate that an action has enough admin approvals to execute
+pub fn validate_approvals(approvals: u32, threshold: u32) -> Result<(), LumentixError> {
+    if approvals < threshold {
+        return Err(LumentixError::ThresholdNotMet);
+    }
+    Ok(())
+}
+
+/// Validate a storage TTL policy: 0 < threshold < extend_to <= max_ttl
+pub fn validate_ttl_config(config: &TtlConfig, max_ttl: u32) -> Result<(), LumentixError> {
+    if config.threshold == 0 || config.threshold >= config.extend_to || config.extend_to > max_ttl {
+        return Err(LumentixError::InvalidTtlConfig);
+    }
+    Ok(())
+}
+
+/// Validate that a basis points value is at most 10000 (100%)
+pub fn validate_bps(bps: u32) -> Result<(), LumentixError> {
+    if bps > 10000 {
+        return Err(LumentixError::InvalidBasisPoints);
+    }
+    Ok(())
+}
+
+/// Calculate `amount * bps / 10000`
+pub fn calculate_bps(amount: i128, bps: u32) -> i128 {
+    amount * bps as i128 / 10000
+}
+
+/// Validate a resale policy: royalty is a share of the price and the price
+/// cap cannot force sellers below face value
+pub fn validate_resale_policy(policy: &ResalePolicy) -> Result<(), LumentixError> {
+    validate_bps(policy.royalty_bps)?;
+    if policy.max_price_bps < 10000 {
+        return Err(LumentixError::InvalidBasisPoints);
+    }
+    Ok(())
+}
+
+/// Validate that a ticket can be listed for resale (not used, not refunded)
+pub fn validate_ticket_resellable(ticket: &Ticket) -> Result<(), LumentixError> {
+    if ticket.used {
+        return Err(LumentixError::TicketAlreadyUsed);
+    }
+    if ticket.refunded {
+        return Err(LumentixError::RefundNotAllowed);
+    }
+    Ok(())
+}
+
+/// Validate a resale price against the event's cap on face value
+pub fn validate_resale_price(
+    face_value: i128,
+    price: i128,
+    policy: &ResalePolicy,
+) -> Result<(), LumentixError> {
+    validate_positive_amount(price)?;
+    if price > calculate_bps(face_value, policy.max_price_bps) {
+        return Err(LumentixError::ResalePriceTooHigh);
+    }
+    Ok(())
+}
+
+/// Split a resale payment into (platform fee, organizer royalty, seller amount).
+/// The fee and royalty together may not exceed the price, so the seller
+/// amount is never negative.
+pub fn split_resale_payment(
+    price: i128,
+    royalty_bps: u32,
+    platform_fee_bps: u32,
+) -> Result<(i128, i128, i128), LumentixError> {
+    validate_bps(royalty_bps.saturating_add(platform_fee_bps))?;
+    let platform_fee = calculate_bps(price, platform_fee_bps);
+    let royalty = calculate_bps(price, royalty_bps);
+    Ok((platform_fee, royalty, price - platform_fee - royalty))
+}
+
+/// Maximum number of payees in a revenue split (bounds the release payout loop)
+pub const MAX_PAYEES: u32 = 10;
+
+/// Validate a revenue split: 1..=MAX_PAYEES distinct payees with non-zero
+/// shares summing to exactly 10000 bps, one of which is the primary payee
+pub fn validate_revenue_split(split: &RevenueSplit) -> Result<(), LumentixError> {
+    if split.payees.is_empty() || split.payees.len() > MAX_PAYEES {
+        return Err(LumentixError::InvalidRevenueSplit);
+    }
+
+    let mut total: u32 = 0;
+    let mut has_primary = false;
+    for (i, payee) in split.payees.iter().enumerate() {
+        if payee.share_bps == 0
+            || split
+                .payees
+                .iter()
+                .skip(i + 1)
+                .any(|other| other.address == payee.address)
+        {
+            return Err(LumentixError::InvalidRevenueSplit);
+        }
+        total = total.saturating_add(payee.share_bps);
+        has_primary |= payee.address == split.primary;
+    }
+
+    if total != 10000 || !has_primary {
+        return Err(LumentixError::InvalidRevenueSplit);
+    }
+    Ok(())
+}
+
+/// Validate that the revenue split can still be changed (locked once Published)
+pub fn validate_revenue_split_editable(event: &Event) -> Result<(), LumentixError> {
+    if event.status != EventStatus::Draft {
+        return Err(LumentixError::InvalidStatusTransition);
+    }
+    Ok(())
+}
+
+/// Split a released escrow amount across the payees, in payee order
+/// Each share is rounded down and the rounding dust is added to the primary payee
+pub fn calculate_payouts(env: &Env, split: &RevenueSplit, amount: i128) -> Vec<i128> {
+    let mut payouts = Vec::new(env);
+    let mut paid = 0;
+    for payee in split.payees.iter() {
+        let share = calculate_bps(amount, payee.share_bps);
+        payouts.push_back(share);
+        paid += share;
+    }
+
+    if let Some(index) = split
+        .payees
+        .iter()
+        .position(|payee| payee.address == split.primary)
+    {
+        let primary_share = payouts.get_unchecked(index as u32);
+        payouts.set(index as u32, primary_share + amount - paid);
+    }
+    payouts
+}
+
+/// Get who receives an escrow payout (an advance or a release) and how much:
+/// split by the event's revenue split, or all to the organizer without one
+/// Advances are paid out this way too, so every payee keeps its share of the
+/// total escrow however it is drawn
+pub fn calculate_escrow_payouts(
+    env: &Env,
+    event: &Event,
+    split: Option<&RevenueSplit>,
+    amount: i128,
+) -> Vec<(Address, i128)> {
+    let mut payouts = Vec::new(env);
+    match split {
+        Some(split) => {
+            let shares = calculate_payouts(env, split, amount);
+            for (payee, share) in split.payees.iter().zip(shares.iter()) {
+                payouts.push_back((payee.address, share));
+            }
+        }
+        None => payouts.push_back((event.organizer.clone(), amount)),
+    }
+    payouts
+}
+
+/// Validate an escrow advance policy: the milestone shares must stay below 100% together
+/// so part of the escrow is always held back until the event completes
+pub fn validate_advance_policy(policy: &AdvancePolicy) -> Result<(), LumentixError> {
+    if policy.published_bps.saturating_add(policy.checkin_bps) >= 10000 {
+        return Err(LumentixError::InvalidAdvancePolicy);
+    }
+    Ok(())
+}
+
+/// Get the share of gross escrow (in bps) unlocked for advances by the milestones reached
+/// Only Published events can draw advances; the rest is released after completion
+pub fn calculate_unlocked_advance_bps(
+    policy: &AdvancePolicy,
+    status: &EventStatus,
+    checkins: u32,
+) -> u32 {
+    if *status != EventStatus::Published {
+        return 0;
+    }
+
+    let mut unlocked = policy.published_bps;
+    if checkins >= policy.checkin_threshold {
+        unlocked += policy.checkin_bps;
+    }
+    unlocked
+}
+
+/// Validate an advance request and return the amount still available to draw
+/// `escrow` is the current balance; unlocked shares apply to escrow plus prior advances
+pub fn validate_advance(
+    amount: i128,
+    escrow: i128,
+    advanced: i128,
+    unlocked_bps: u32,
+) -> Result<i128, LumentixError> {
+    validate_positive_amount(amount)?;
+    let available = (calculate_bps(escrow + advanced, unlocked_bps) - advanced).max(0);
+    if amount > available {
+        return Err(LumentixError::AdvanceLimitExceeded);
+    }
+    Ok(available - amount)
+}
+
+/// Get the refund shortfall of a cancelled event: advances drawn and not repaid
+/// are missing from escrow, so refunds beyond the balance are underfunded
+pub fn calculate_refund_shortfall(event: &Event, advanced: i128) -> i128 {
+    if event.status == EventStatus::Cancelled {
+        advanced.max(0)
+    } else {
+        0
+    }
+}
+
+/// Maximum length in bytes of a dispute reason stored on-chain
+pub const MAX_DISPUTE_REASON_LEN: u32 = 280;
+
+/// Validate that a ticket holder can open a dispute for an event
+/// Disputes are open from `end_time` until `end_time + window` to holders of
+/// unrefunded tickets (checked in or not), once per event
+pub fn validate_dispute_opening(
+    event: &Event,
+    ticket: &Ticket,
+    caller: &Address,
+    reason: &String,
+    existing: Option<&Dispute>,
+    now: u64,
+    window: u64,
+) -> Result<(), LumentixError> {
+    validate_string_not_empty(reason)?;
+    if reason.len() > MAX_DISPUTE_REASON_LEN {
+        return Err(LumentixError::StringTooLong);
+    }
+    if event.status != EventStatus::Published && event.status != EventStatus::Completed {
+        return Err(LumentixError::InvalidStatusTransition);
+    }
+    if ticket.event_id != event.id || ticket.owner != *caller {
+        return Err(LumentixError::Unauthorized);
+    }
+    if ticket.refunded {
+        return Err(LumentixError::RefundNotAllowed);
+    }
+    if now < event.end_time || now > event.end_time.saturating_add(window) {
+        return Err(LumentixError::DisputeWindowClosed);
+    }
+    if existing.is_some() {
+        return Err(LumentixError::DisputeAlreadyOpen);
+    }
+    Ok(())
+}
+
+/// Validate an admin ruling against the event's dispute
+pub fn validate_dispute_resolution(
+    dispute: Option<&Dispute>,
+    resolution: &DisputeResolution,
+) -> Result<(), LumentixError> {
+    if dispute.is_none_or(|dispute| dispute.status != DisputeStatus::Open) {
+        return Err(LumentixError::DisputeNotFound);
+    }
+    if let DisputeResolution::PartialRefund(refund_bps) = resolution {
+        validate_bps(*refund_bps)?;
+    }
+    Ok(())
+}
+
+/// Validate that escrow can be released: the dispute window must have passed
+/// and any dispute must be resolved in a way that leaves the organizer something
+pub fn validate_escrow_release(
+    event: &Event,
+    dispute: Option<&Dispute>,
+    now: u64,
+    window: u64,
+) -> Result<(), LumentixError> {
+    match dispute {
+        Some(dispute) => match dispute.status {
+            DisputeStatus::Open | DisputeStatus::Resolved(DisputeResolution::FullRefund) => {
+                Err(LumentixError::EscrowFrozen)
+            }
+            DisputeStatus::Resolved(_) => Ok(()),
+        },
+        None if now <= event.end_time.saturating_add(window) => Err(LumentixError::EscrowFrozen),
+        None => Ok(()),
+    }
+}
+
+/// Get the total holders are refunded under a dispute ruling
+/// Refunds are owed on everything escrowed, so `escrowed` includes advances
+/// already drawn. Partial refunds are rounded down per ticket, so `escrowed * bps`
+/// covers them
+fn calculate_dispute_refunds_owed(escrowed: i128, resolution: &DisputeResolution) -> i128 {
+    match resolution {
+        DisputeResolution::FullRelease => 0,
+        DisputeResolution::PartialRefund(refund_bps) => calculate_bps(escrowed, *refund_bps),
+        DisputeResolution::FullRefund => escrowed,
+    }
+}
+
+/// Get how much of the advances must be repaid (see `repay_advance`) before
+/// the escrow balance covers the refunds owed under a dispute ruling
+pub fn calculate_dispute_shortfall(
+    escrow: i128,
+    advanced: i128,
+    resolution: &DisputeResolution,
+) -> i128 {
+    (calculate_dispute_refunds_owed(escrow + advanced, resolution) - escrow).max(0)
+}
+
+/// Get the part of the escrow balance the organizer receives under a dispute ruling
+/// `escrow` is the current balance, net of `advanced`. Release is blocked while
+/// the balance cannot cover the refunds owed
+pub fn calculate_dispute_release(
+    escrow: i128,
+    advanced: i128,
+    resolution: &DisputeResolution,
+) -> Result<i128, LumentixError> {
+    if calculate_dispute_shortfall(escrow, advanced, resolution) > 0 {
+        return Err(LumentixError::RefundUnderfunded);
+    }
+    Ok(escrow - calculate_dispute_refunds_owed(escrow + advanced, resolution))
+}
+
+/// Get a holder's refund under a dispute ruling: a share of what was escrowed
+/// for the ticket (the platform fee is not refunded)
+pub fn calculate_dispute_refund(ticket: &Ticket, resolution: &DisputeResolution) -> i128 {
+    let escrowed = ticket.price_paid - ticket.platform_fee;
+    match resolution {
+        DisputeResolution::FullRelease => 0,
+        DisputeResolution::PartialRefund(refund_bps) => calculate_bps(escrowed, *refund_bps),
+        DisputeResolution::FullRefund => escrowed,
+    }
+}
+
+/// Validate a refund schedule: rules ordered from the earliest cutoff (largest
+/// `cutoff_secs`) to the latest, with refund percentages that never increase
+pub fn validate_refund_schedule(schedule: &Vec<RefundRule>) -> Result<(), LumentixError> {
+    let mut previous: Option<RefundRule> = None;
+    for rule in schedule.iter() {
+        validate_bps(rule.refund_bps)?;
+        if let Some(prev) = &previous {
+            if rule.cutoff_secs >= prev.cutoff_secs || rule.refund_bps > prev.refund_bps {
+                return Err(LumentixError::InvalidRefundSchedule);
+            }
+        }
+        previous = Some(rule);
+    }
+    Ok(())
+}
+
+/// Validate that the refund schedule can still be changed (locked once Published)
+pub fn validate_refund_schedule_editable(event: &Event) -> Result<(), LumentixError> {
+    if event.status != EventStatus::Draft {
+        return Err(LumentixError::InvalidStatusTransition);
+    }
+    Ok(())
+}
+
+/// Get the refund percentage (in bps) that applies at ledger time `now`
+/// Returns 0 once every cutoff has passed or when no schedule is set
+pub fn calculate_refund_bps(schedule: &Vec<RefundRule>, start_time: u64, now: u64) -> u32 {
+    for rule in schedule.iter() {
+        if now <= start_time.saturating_sub(rule.cutoff_secs) {
+            return rule.refund_bps;
+        }
+    }
+    0
+}
+
+/// Get a holder's scheduled refund at ledger time `now`. The refund base is
+/// what was escrowed for the ticket (the platform fee is not refunded), so
+/// refunding every ticket at 100% drains escrow exactly.
+pub fn calculate_scheduled_refund(
+    ticket: &Ticket,
+    schedule: &Vec<RefundRule>,
+    start_time: u64,
+    now: u64,
+) -> i128 {
+    let escrowed = ticket.price_paid - ticket.platform_fee;
+    calculate_bps(escrowed, calculate_refund_bps(schedule, start_time, now))
+}
+
+/// How long a waitlisted buyer has to accept an offer (in seconds)
+pub const WAITLIST_OFFER_WINDOW: u64 = 3600;
+
+/// Validate that a waitlist offer belongs to `buyer` and has not expired at `now`
+pub fn validate_waitlist_offer(
+    offer: &WaitlistOffer,
+    buyer: &Address,
+    now: u64,
+) -> Result<(), LumentixError> {
+    if offer.buyer != *buyer || now > offer.expires_at {
+        return Err(LumentixError::NoActiveOffer);
+    }
+    Ok(())
+}
+
+/// Hash a promo code preimage the same way organizers register it (sha256)
+pub fn hash_promo_code(env: &Env, code: &Bytes) -> BytesN<32> {
+    env.crypto().sha256(code).into()
+}
+
+/// Validate a discount: percentages up to 100%, fixed amounts positive
+pub fn validate_discount(discount: &Discount) -> Result<(), LumentixError> {
+    match discount {
+        Discount::Percentage(bps) => validate_bps(*bps),
+        Discount::Fixed(amount) => validate_positive_amount(*amount),
+    }
+}
+
+/// Validate that a promo code can still be redeemed at ledger time `now`
+pub fn validate_promo_code(promo: &PromoCode, now: u64) -> Result<(), LumentixError> {
+    if now > promo.expires_at || promo.redemptions >= promo.max_redemptions {
+        return Err(LumentixError::PromoCodeUnavailable);
+    }
+    Ok(())
+}
+
+/// Apply a discount to a price, never going below zero
+pub fn apply_discount(price: i128, discount: &Discount) -> i128 {
+    let discounted = match discount {
+        Discount::Percentage(bps) => price - calculate_bps(price, *bps),
+        Discount::Fixed(amount) => price - amount,
+    };
+    discounted.max(0)
+}
+
+/// Check if an event is still in its allowlist presale phase at ledger time `now`
+pub fn is_presale_active(presale: &Option<PresaleConfig>, now: u64) -> bool {
+    match presale {
+        Some(presale) => now < presale.public_sale_time,
+        None => false,
+    }
+}
+
+/// Merkle leaf for an allowlisted address: sha256 of its XDR encoding
+pub fn presale_leaf(env: &Env, buyer: &Address) -> BytesN<32> {
+    env.crypto().sha256(&buyer.clone().to_xdr(env)).into()
+}
+
+/// Verify a Merkle proof with sorted-pair sha256 hashing
+pub fn verify_merkle_proof(
+    env: &Env,
+    root: &BytesN<32>,
+    leaf: &BytesN<32>,
+    proof: &Vec<BytesN<32>>,
+) -> bool {
+    let mut computed = leaf.clone();
+    for sibling in proof.iter() {
+        let (left, right) = if computed <= sibling {
+            (computed, sibling)
+        } else {
+            (sibling, computed)
+        };
+
+        let mut data = Bytes::new(env);
+        data.extend_from_array(&left.to_array());
+        data.extend_from_array(&right.to_array());
+        computed = env.crypto().sha256(&data).into();
+    }
+    computed == *root
+}
+
+/// Validate a presale purchase: the buyer must prove allowlist membership and
+/// stay within the per-address limit including tickets already bought
+pub fn validate_presale_purchase(
+    env: &Env,
+    presale: &PresaleConfig,
+    buyer: &Address,
+    proof: &Vec<BytesN<32>>,
+    already_bought: u32,
+    quantity: u32,
+) -> Result<(), LumentixError> {
+    let leaf = presale_leaf(env, buyer);
+    if !verify_merkle_proof(env, &presale.merkle_root, &leaf, proof) {
+        return Err(LumentixError::NotAllowlisted);
+    }
+    if already_bought.saturating_add(quantity) > presale.per_address_limit {
+        return Err(LumentixError::PresaleLimitExceeded);
+    }
+    Ok(())
+}
+
+/// Validate Dutch auction parameters
+pub fn validate_auction(auction: &DutchAuction) -> Result<(), LumentixError> {
+    if auction.floor_price <= 0
+        || auction.start_price <= auction.floor_price
+        || auction.price_step <= 0
+        || auction.decay_period == 0
+    {
+        return Err(LumentixError::InvalidAuction);
+    }
+    Ok(())
+}
+
+/// Calculate the Dutch auction price at ledger time `now`
+pub fn calculate_auction_price(auction: &DutchAuction, now: u64) -> i128 {
+    let elapsed = now.saturating_sub(auction.start_time);
+    let steps = (elapsed / auction.decay_period) as i128;
+    let price = auction
+        .start_price
+        .saturating_sub(steps.saturating_mul(auction.price_step));
+    price.max(auction.floor_price)
+}
+
+/// Ledger time at which the auction price reaches its floor
+/// Saturates at u64::MAX for auctions that would never reach it in ledger time
+pub fn auction_end_time(auction: &DutchAuction) -> u64 {
+    let range = auction.start_price - auction.floor_price;
+    let mut steps = range / auction.price_step;
+    if range % auction.price_step != 0 {
+        steps += 1;
+    }
+
+    let steps = u64::try_from(steps).unwrap_or(u64::MAX);
+    auction
+        .start_time
+        .saturating_add(steps.saturating_mul(auction.decay_period))
+}
+
+/// Calculate the rebate owed to a buyer under uniform clearing pricing as
+/// (escrow part, platform fee part). The ticket is repriced at the clearing
+/// price with the same fee rate, so escrow only returns the net difference and
+/// the fee taken on the rebated amount is reversed.
+/// The auction ends when the floor is reached or the event sells out.
+pub fn calculate_clearing_rebate(
+    event: &Event,
+    auction: &DutchAuction,
+    ticket: &Ticket,
+    clearing_price: i128,
+    now: u64,
+) -> Result<(i128, i128), LumentixError> {
+    if !auction.uniform_clearing {
+        return Ok((0, 0));
+    }
+    if now < auction_end_time(auction) && event_tickets_sold(event) < event_capacity(event) {
+        return Err(LumentixError::AuctionNotEnded);
+    }
+    if ticket.refunded {
+        return Err(LumentixError::RefundNotAllowed);
+    }
+    if clearing_price >= ticket.price_paid {
+        return Ok((0, 0));
+    }
+
+    let fee_at_clearing = ticket
+        .platform_fee
+        .checked_mul(clearing_price)
+        .ok_or(LumentixError::InvalidAmount)?
+        / ticket.price_paid;
+    let fee_rebate = ticket.platform_fee - fee_at_clearing;
+    Ok((ticket.price_paid - clearing_price - fee_rebate, fee_rebate))
+}
+
+/// Validate an event status transition against the lifecycle table:
+/// - Draft -> Published
+/// - Published -> Completed | Cancelled | Postponed
+/// - Postponed -> Published | Cancelled
+/// - Completed and Cancelled are final
+///
+/// Time-based conditions (end time reached, opt-out window over) are checked by the caller.
+pub fn validate_status_transition(
+    from: &EventStatus,
+    to: &EventStatus,
+) -> Result<(), LumentixError> {
+    let allowed = matches!(
+        (from, to),
+        (EventStatus::Draft, EventStatus::Published)
+            | (EventStatus::Published, EventStatus::Completed)
+            | (EventStatus::Published, EventStatus::Cancelled)
+            | (EventStatus::Published, EventStatus::Postponed)
+            | (EventStatus::Postponed, EventStatus::Published)
+            | (EventStatus::Postponed, EventStatus::Cancelled)
+    );
+    if !allowed {
+        return Err(LumentixError::InvalidStatusTransition);
+    }
+    Ok(())
+}
+
+/// Postpone a published event to new times, opening an opt-out refund window of
+/// `opt_out_window` seconds from `now`. Returns the postponement record to store.
+pub fn postpone_event(
+    event: &mut Event,
+    new_start_time: u64,
+    new_end_time: u64,
+    opt_out_window: u64,
+    now: u64,
+) -> Result<Postponement, LumentixError> {
+    validate_status_transition(&event.status, &EventStatus::Postponed)?;
+    validate_time_range(new_start_time, new_end_time)?;
+    let opt_out_deadline = now.saturating_add(opt_out_window);
+    if new_start_time <= opt_out_deadline {
+        return Err(LumentixError::InvalidTimeRange);
+    }
+
+    let postponement = Postponement {
+        event_id: event.id,
+        original_start_time: event.start_time,
+        original_end_time: event.end_time,
+        opt_out_deadline,
+    };
+    event.start_time = new_start_time;
+    event.end_time = new_end_time;
+    event.status = EventStatus::Postponed;
+    Ok(postponement)
+}
+
+/// Validate that a holder can still opt out of a postponed event for a full refund
+pub fn validate_opt_out(
+    event: &Event,
+    postponement: &Postponement,
+    now: u64,
+) -> Result<(), LumentixError> {
+    if event.status != EventStatus::Postponed {
+        return Err(LumentixError::InvalidStatusTransition);
+    }
+    if now > postponement.opt_out_deadline {
+        return Err(LumentixError::OptOutWindowClosed);
+    }
+    Ok(())
+}
+
+/// Validate that a postponed event can return to Published (opt-out window over)
+pub fn validate_postponement_ended(
+    postponement: &Postponement,
+    now: u64,
+) -> Result<(), LumentixError> {
+    if now <= postponement.opt_out_deadline {
+        return Err(LumentixError::OptOutWindowOpen);
+    }
+    Ok(())
+}
+
+/// Apply an organizer's update to an event, enforcing status-aware restrictions:
+/// - Draft: any field may change
+/// - Published: metadata and capacity increases only (never below the tier's sales)
+/// - Postponed / Completed / Cancelled: no changes
+///
+/// Price and capacity changes apply to the tier named by `tier_id`. Every check
+/// runs before any field is assigned, so a rejected update leaves the event as is.
+pub fn apply_event_update(event: &mut Event, update: &EventUpdate) -> Result<(), LumentixError> {
+    match event.status {
+        EventStatus::Draft => {}
+        EventStatus::Published => {
+            if update.start_time.is_some()
+                || update.end_time.is_some()
+                || update.ticket_price.is_some()
+            {
+                return Err(LumentixError::UpdateNotAllowed);
+            }
+        }
+        _ => return Err(LumentixError::UpdateNotAllowed),
+    }
+
+    if let Some(name) = &update.name {
+        validate_string_not_empty(name)?;
+    }
+    let start_time = update.start_time.unwrap_or(event.start_time);
+    let end_time = update.end_time.unwrap_or(event.end_time);
+    validate_time_range(start_time, end_time)?;
+    if let Some(ticket_price) = update.ticket_price {
+        validate_positive_amount(ticket_price)?;
+    }
+
+    let tier = match update.tier_id {
+        Some(tier_id) => Some(find_tier(event, tier_id)?),
+        None if update.ticket_price.is_none() && update.max_tickets.is_none() => None,
+        None => return Err(LumentixError::TierNotFound),
+    };
+    if let (Some(max_tickets), Some((_, tier))) = (update.max_tickets, &tier) {
+        validate_positive_capacity(max_tickets)?;
+        if event.status == EventStatus::Published && max_tickets < tier.max_tickets {
+            return Err(LumentixError::UpdateNotAllowed);
+        }
+        if max_tickets < tier.tickets_sold {
+            return Err(LumentixError::CapacityExceeded);
+        }
+    }
+
+    if let Some(name) = &update.name {
+        event.name = name.clone();
+    }
+    if let Some(description) = &update.description {
+        event.description = description.clone();
+    }
+    if let Some(location) = &update.location {
+        event.location = location.clone();
+    }
+    event.start_time = start_time;
+    event.end_time = end_time;
+    if let Some((index, mut tier)) = tier {
+        if let Some(ticket_price) = update.ticket_price {
+            tier.price = ticket_price;
+        }
+        if let Some(max_tickets) = update.max_tickets {
+            tier.max_tickets = max_tickets;
+        }
+        event.tiers.set(index, tier);
+    }
+    Ok(())
+}
+
+/// Validate a pricing curve: positive linear steps, or thresholds with strictly
+/// ascending fill levels up to 100% and non-decreasing bumps
+pub fn validate_pricing_curve(curve: &PricingCurve) -> Result<(), LumentixError> {
+    match curve {
+        PricingCurve::LinearSteps(every, step) => {
+            if *every == 0 || *step <= 0 {
+                return Err(LumentixError::InvalidPricingCurve);
+            }
+        }
+        PricingCurve::Thresholds(thresholds) => {
+            let mut previous: Option<(u32, u32)> = None;
+            for threshold in thresholds.iter() {
+                validate_bps(threshold.fill_bps)?;
+                if let Some((fill, bump)) = previous {
+                    if threshold.fill_bps <= fill || threshold.bump_bps < bump {
+                        return Err(LumentixError::InvalidPricingCurve);
+                    }
+                }
+                previous = Some((threshold.fill_bps, threshold.bump_bps));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Calculate the curve price of the next ticket given how many are already sold
+pub fn calculate_curve_price(
+    base_price: i128,
+    curve: &PricingCurve,
+    tickets_sold: u32,
+    max_tickets: u32,
+) -> Result<i128, LumentixError> {
+    let bump = match curve {
+        PricingCurve::LinearSteps(every, step) => {
+            ((tickets_sold / every) as i128).checked_mul(*step)
+        }
+        PricingCurve::Thresholds(thresholds) => {
+            let fill_bps = (tickets_sold as u64 * 10000 / max_tickets.max(1) as u64) as u32;
+            let mut bump_bps = 0;
+            for threshold in thresholds.iter() {
+                if fill_bps >= threshold.fill_bps {
+                    bump_bps = threshold.bump_bps;
+                }
+            }
+            base_price
+                .checked_mul(bump_bps as i128)
+                .map(|scaled| scaled / 10000)
+        }
+    };
+
+    bump.and_then(|bump| base_price.checked_add(bump))
+        .ok_or(LumentixError::InvalidAmount)
+}
+
+/// Quote the exact total for `quantity` tickets bought in one purchase,
+/// pricing each ticket at its position on the curve
+pub fn quote_price(
+    base_price: i128,
+    curve: &Option<PricingCurve>,
+    tickets_sold: u32,
+    max_tickets: u32,
+    quantity: u32,
+) -> Result<i128, LumentixError> {
+    validate_purchase_quantity(quantity)?;
+    if tickets_sold.saturating_add(quantity) > max_tickets {
+        return Err(LumentixError::EventSoldOut);
+    }
+
+    match curve {
+        Some(curve) => {
+            let mut total: i128 = 0;
+            for i in 0..quantity {
+                let price =
+                    calculate_curve_price(base_price, curve, tickets_sold + i, max_tickets)?;
+                total = total
+                    .checked_add(price)
+                    .ok_or(LumentixError::InvalidAmount)?;
+            }
+            Ok(total)
+        }
+        None => calculate_total_price(base_price, quantity),
+    }
+}
+
+/// Validate an event's accepted payment tokens: positive prices, tiers that
+/// exist on the event, and at most one price per (tier, token)
+pub fn validate_accepted_tokens(
+    tokens: &Vec<AcceptedToken>,
+    tiers: &Vec<TicketTier>,
+) -> Result<(), LumentixError> {
+    for (i, accepted) in tokens.iter().enumerate() {
+        validate_positive_amount(accepted.price)?;
+        if !tiers.iter().any(|tier| tier.id == accepted.tier_id) {
+            return Err(LumentixError::TierNotFound);
+        }
+        for other in tokens.iter().skip(i + 1) {
+            if other.token == accepted.token && other.tier_id == accepted.tier_id {
+                return Err(LumentixError::DuplicateToken);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Find the price of a tier's tickets in a payment token
+/// Events without an accepted-token list only take the default token, at the
+/// tier's price
+pub fn find_accepted_token(
+    event: &Event,
+    tier_id: u32,
+    token: &Address,
+    default_token: &Address,
+) -> Result<AcceptedToken, LumentixError> {
+    if event.accepted_tokens.is_empty() {
+        if token != default_token {
+            return Err(LumentixError::TokenNotAccepted);
+        }
+        return Ok(AcceptedToken {
+            token: token.clone(),
+            tier_id,
+            price: find_tier(event, tier_id)?.1.price,
+        });
+    }
+
+    event
+        .accepted_tokens
+        .iter()
+        .find(|accepted| accepted.token == *token && accepted.tier_id == tier_id)
+        .ok_or(LumentixError::TokenNotAccepted)
+}
+
 /// Validate that start time is before end time
 pub fn validate_time_range(start_time: u64, end_time: u64) -> Result<(), LumentixError> {
     if start_time >= end_time {
@@ -35,17 +909,80 @@ pub fn validate_time_range(start_time: u64, end_time: u64) -> Result<(), Lumenti
 
 /// Validate that a string is not empty
 pub fn validate_string_not_empty(s: &String) -> Result<(), LumentixError> {
-    if s.len() == 0 {
+    if s.is_empty() {
         return Err(LumentixError::EmptyString);
     }
     Ok(())
 }
 
+/// Validate the ticket tiers of an event: at least one tier, unique IDs,
+/// non-empty names, positive prices and positive capacities
+pub fn validate_tiers(tiers: &Vec<TicketTier>) -> Result<(), LumentixError> {
+    validate_positive_capacity(tiers.len())?;
+
+    for (i, tier) in tiers.iter().enumerate() {
+        validate_string_not_empty(&tier.name)?;
+        validate_positive_amount(tier.price)?;
+        validate_positive_capacity(tier.max_tickets)?;
+
+        for other in tiers.iter().skip(i + 1) {
+            if other.id == tier.id {
+                return Err(LumentixError::DuplicateTier);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Find a ticket tier of an event by its ID
+pub fn find_tier(event: &Event, tier_id: u32) -> Result<(u32, TicketTier), LumentixError> {
+    for (index, tier) in event.tiers.iter().enumerate() {
+        if tier.id == tier_id {
+            return Ok((index as u32, tier));
+        }
+    }
+    Err(LumentixError::TierNotFound)
+}
+
+/// Get an event's total capacity across its tiers
+pub fn event_capacity(event: &Event) -> u32 {
+    event
+        .tiers
+        .iter()
+        .fold(0u32, |total, tier| total.saturating_add(tier.max_tickets))
+}
+
+/// Get the number of tickets sold for an event across its tiers
+pub fn event_tickets_sold(event: &Event) -> u32 {
+    event
+        .tiers
+        .iter()
+        .fold(0u32, |total, tier| total.saturating_add(tier.tickets_sold))
+}
+
+/// Validate that a tier still has capacity for `quantity` more tickets
+pub fn validate_tier_capacity(tier: &TicketTier, quantity: u32) -> Result<(), LumentixError> {
+    if tier.tickets_sold.saturating_add(quantity) > tier.max_tickets {
+        return Err(LumentixError::EventSoldOut);
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use soroban_sdk::{Env, String};
 
+    fn tier(env: &Env, id: u32, name: &str, price: i128, max_tickets: u32) -> TicketTier {
+        TicketTier {
+            id,
+            name: String::from_str(env, name),
+            price,
+            max_tickets,
+            tickets_sold: 0,
+        }
+    }
+
     #[test]
     fn test_validate_positive_amount() {
         assert!(validate_positive_amount(100).is_ok());
@@ -96,4 +1033,1330 @@ mod tests {
             Err(LumentixError::EmptyString)
         );
     }
+
+    #[test]
+    fn test_validate_purchase_quantity() {
+        assert!(validate_purchase_quantity(1).is_ok());
+        assert!(validate_purchase_quantity(MAX_BATCH_PURCHASE).is_ok());
+        assert_eq!(
+            validate_purchase_quantity(0),
+            Err(LumentixError::InvalidQuantity)
+        );
+        assert_eq!(
+            validate_purchase_quantity(MAX_BATCH_PURCHASE + 1),
+            Err(LumentixError::InvalidQuantity)
+        );
+    }
+
+    #[test]
+    fn test_calculate_total_price() {
+        assert_eq!(calculate_total_price(100, 5), Ok(500));
+        assert_eq!(
+            calculate_total_price(i128::MAX, 2),
+            Err(LumentixError::InvalidAmount)
+        );
+    }
+
+    #[test]
+    fn test_validate_resale_price() {
+        let policy = ResalePolicy {
+            max_price_bps: 11000,
+            royalty_bps: 500,
+        };
+        assert!(validate_resale_policy(&policy).is_ok());
+        assert!(validate_resale_price(100, 110, &policy).is_ok());
+        assert_eq!(
+            validate_resale_price(100, 111, &policy),
+            Err(LumentixError::ResalePriceTooHigh)
+        );
+        assert_eq!(
+            validate_resale_price(100, 0, &policy),
+            Err(LumentixError::InvalidAmount)
+        );
+
+        let below_face = ResalePolicy {
+            max_price_bps: 9000,
+            royalty_bps: 500,
+        };
+        assert_eq!(
+            validate_resale_policy(&below_face),
+            Err(LumentixError::InvalidBasisPoints)
+        );
+    }
+
+    #[test]
+    fn test_split_resale_payment() {
+        // 5% platform fee, 10% royalty on a 200 resale
+        assert_eq!(split_resale_payment(200, 1000, 500), Ok((10, 20, 170)));
+        assert_eq!(split_resale_payment(200, 0, 0), Ok((0, 0, 200)));
+        // Fee and royalty may take the whole price, but no more
+        assert_eq!(split_resale_payment(200, 9500, 500), Ok((10, 190, 0)));
+        assert_eq!(
+            split_resale_payment(200, 9600, 500),
+            Err(LumentixError::InvalidBasisPoints)
+        );
+    }
+
+    #[test]
+    fn test_validate_ticket_resellable() {
+        use soroban_sdk::testutils::Address as _;
+
+        let env = Env::default();
+        let mut listed = ticket(&env, &Address::generate(&env));
+        listed.used = false;
+        assert!(validate_ticket_resellable(&listed).is_ok());
+
+        let mut used = listed.clone();
+        used.used = true;
+        assert_eq!(
+            validate_ticket_resellable(&used),
+            Err(LumentixError::TicketAlreadyUsed)
+        );
+
+        let mut refunded = listed;
+        refunded.refunded = true;
+        assert_eq!(
+            validate_ticket_resellable(&refunded),
+            Err(LumentixError::RefundNotAllowed)
+        );
+    }
+
+    fn revenue_split(env: &Env, shares: &[u32], primary: usize) -> RevenueSplit {
+        use crate::types::Payee;
+        use soroban_sdk::testutils::Address as _;
+
+        let mut payees = Vec::new(env);
+        for share_bps in shares {
+            payees.push_back(Payee {
+                address: Address::generate(env),
+                share_bps: *share_bps,
+            });
+        }
+        let primary = payees
+            .get(primary as u32)
+            .map(|payee| payee.address)
+            .unwrap_or_else(|| Address::generate(env));
+        RevenueSplit { payees, primary }
+    }
+
+    #[test]
+    fn test_validate_revenue_split() {
+        let env = Env::default();
+        assert!(validate_revenue_split(&revenue_split(&env, &[6000, 3000, 1000], 0)).is_ok());
+        assert!(validate_revenue_split(&revenue_split(&env, &[10000], 0)).is_ok());
+
+        let invalid: [&[u32]; 4] = [&[6000, 3000], &[6000, 4000, 1000], &[10000, 0], &[]];
+        for shares in invalid {
+            assert_eq!(
+                validate_revenue_split(&revenue_split(&env, shares, 0)),
+                Err(LumentixError::InvalidRevenueSplit)
+            );
+        }
+
+        // Primary must be one of the payees
+        let mut split = revenue_split(&env, &[5000, 5000], 0);
+        split.primary = revenue_split(&env, &[10000], 0).primary;
+        assert_eq!(
+            validate_revenue_split(&split),
+            Err(LumentixError::InvalidRevenueSplit)
+        );
+
+        // Duplicate payees are rejected
+        let mut split = revenue_split(&env, &[5000, 5000], 0);
+        let first = split.payees.get_unchecked(0);
+        let mut second = split.payees.get_unchecked(1);
+        second.address = first.address;
+        split.payees.set(1, second);
+        assert_eq!(
+            validate_revenue_split(&split),
+            Err(LumentixError::InvalidRevenueSplit)
+        );
+    }
+
+    #[test]
+    fn test_calculate_payouts_sends_dust_to_primary() {
+        let env = Env::default();
+        let split = revenue_split(&env, &[3333, 3333, 3334], 1);
+
+        // 3333 bps of 100 rounds down to 33; the 1 unit of dust goes to payee 1
+        assert_eq!(
+            calculate_payouts(&env, &split, 100),
+            Vec::from_array(&env, [33i128, 34, 33])
+        );
+        assert_eq!(
+            calculate_payouts(&env, &split, 10000),
+            Vec::from_array(&env, [3333i128, 3333, 3334])
+        );
+    }
+
+    #[test]
+    fn test_advances_are_split_like_releases() {
+        let env = Env::default();
+        let event = event(&env, EventStatus::Published);
+        let split = revenue_split(&env, &[7000, 3000], 0);
+        let primary = split.primary.clone();
+        let partner = split.payees.get(1).unwrap().address;
+
+        // 301 advanced, then the remaining 700 released: each payee ends up
+        // with its share of the 1001 total, the dust going to the primary
+        let advance = calculate_escrow_payouts(&env, &event, Some(&split), 301);
+        assert_eq!(
+            advance,
+            Vec::from_array(&env, [(primary.clone(), 211i128), (partner.clone(), 90)])
+        );
+        let release = calculate_escrow_payouts(&env, &event, Some(&split), 700);
+        assert_eq!(
+            release,
+            Vec::from_array(&env, [(primary, 490i128), (partner, 210)])
+        );
+
+        // Without a split everything goes to the organizer
+        assert_eq!(
+            calculate_escrow_payouts(&env, &event, None, 301),
+            Vec::from_array(&env, [(event.organizer.clone(), 301i128)])
+        );
+    }
+
+    #[test]
+    fn test_validate_revenue_split_editable() {
+        let env = Env::default();
+        assert!(validate_revenue_split_editable(&event(&env, EventStatus::Draft)).is_ok());
+        assert_eq!(
+            validate_revenue_split_editable(&event(&env, EventStatus::Published)),
+            Err(LumentixError::InvalidStatusTransition)
+        );
+    }
+
+    fn advance_policy() -> AdvancePolicy {
+        // 20% once published, another 30% after 100 check-ins
+        AdvancePolicy {
+            published_bps: 2000,
+            checkin_threshold: 100,
+            checkin_bps: 3000,
+        }
+    }
+
+    #[test]
+    fn test_validate_advance_policy() {
+        assert!(validate_advance_policy(&advance_policy()).is_ok());
+
+        let mut policy = advance_policy();
+        policy.checkin_bps = 7999;
+        assert!(validate_advance_policy(&policy).is_ok());
+
+        // Advancing the whole escrow would leave nothing to cover refunds
+        policy.checkin_bps = 8000;
+        assert_eq!(
+            validate_advance_policy(&policy),
+            Err(LumentixError::InvalidAdvancePolicy)
+        );
+
+        policy.checkin_bps = 8001;
+        assert_eq!(
+            validate_advance_policy(&policy),
+            Err(LumentixError::InvalidAdvancePolicy)
+        );
+    }
+
+    #[test]
+    fn test_calculate_unlocked_advance_bps() {
+        let policy = advance_policy();
+        let published = EventStatus::Published;
+
+        assert_eq!(calculate_unlocked_advance_bps(&policy, &published, 0), 2000);
+        assert_eq!(
+            calculate_unlocked_advance_bps(&policy, &published, 99),
+            2000
+        );
+        assert_eq!(
+            calculate_unlocked_advance_bps(&policy, &published, 100),
+            5000
+        );
+        assert_eq!(
+            calculate_unlocked_advance_bps(&policy, &EventStatus::Draft, 500),
+            0
+        );
+        assert_eq!(
+            calculate_unlocked_advance_bps(&policy, &EventStatus::Cancelled, 500),
+            0
+        );
+    }
+
+    #[test]
+    fn test_validate_advance() {
+        // 1000 escrowed, 20% unlocked: 200 available
+        assert_eq!(validate_advance(150, 1000, 0, 2000), Ok(50));
+        // After drawing 150, the share applies to the gross 1000 again
+        assert_eq!(validate_advance(50, 850, 150, 2000), Ok(0));
+        assert_eq!(
+            validate_advance(51, 850, 150, 2000),
+            Err(LumentixError::AdvanceLimitExceeded)
+        );
+        // Refunds shrank escrow below the advanced share: nothing more to draw
+        assert_eq!(
+            validate_advance(1, 500, 200, 2000),
+            Err(LumentixError::AdvanceLimitExceeded)
+        );
+        assert_eq!(
+            validate_advance(0, 1000, 0, 2000),
+            Err(LumentixError::InvalidAmount)
+        );
+    }
+
+    #[test]
+    fn test_calculate_refund_shortfall() {
+        let env = Env::default();
+        assert_eq!(
+            calculate_refund_shortfall(&event(&env, EventStatus::Cancelled), 200),
+            200
+        );
+        assert_eq!(
+            calculate_refund_shortfall(&event(&env, EventStatus::Published), 200),
+            0
+        );
+    }
+
+    fn ticket(env: &Env, owner: &Address) -> Ticket {
+        use soroban_sdk::testutils::Address as _;
+
+        Ticket {
+            id: 1,
+            event_id: 1,
+            tier_id: 0,
+            owner: owner.clone(),
+            payment_token: Address::generate(env),
+            price_paid: 100,
+            platform_fee: 5,
+            purchase_time: 500,
+            used: true,
+            refunded: false,
+        }
+    }
+
+    fn dispute(env: &Env, status: DisputeStatus) -> Dispute {
+        use soroban_sdk::testutils::Address as _;
+
+        Dispute {
+            event_id: 1,
+            opened_by: Address::generate(env),
+            ticket_id: 1,
+            reason: String::from_str(env, "No-show"),
+            opened_at: 2100,
+            status,
+            resolved_at: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_dispute_opening() {
+        use soroban_sdk::testutils::Address as _;
+
+        let env = Env::default();
+        let event = event(&env, EventStatus::Completed);
+        let holder = Address::generate(&env);
+        let ticket = ticket(&env, &holder);
+        let reason = String::from_str(&env, "Headliner never showed up");
+
+        // Event ends at 2000 with a 1000 second window
+        assert!(
+            validate_dispute_opening(&event, &ticket, &holder, &reason, None, 2000, 1000).is_ok()
+        );
+        assert!(
+            validate_dispute_opening(&event, &ticket, &holder, &reason, None, 3000, 1000).is_ok()
+        );
+        for now in [1999, 3001] {
+            assert_eq!(
+                validate_dispute_opening(&event, &ticket, &holder, &reason, None, now, 1000),
+                Err(LumentixError::DisputeWindowClosed)
+            );
+        }
+
+        let stranger = Address::generate(&env);
+        assert_eq!(
+            validate_dispute_opening(&event, &ticket, &stranger, &reason, None, 2500, 1000),
+            Err(LumentixError::Unauthorized)
+        );
+
+        let existing = dispute(&env, DisputeStatus::Open);
+        assert_eq!(
+            validate_dispute_opening(
+                &event,
+                &ticket,
+                &holder,
+                &reason,
+                Some(&existing),
+                2500,
+                1000
+            ),
+            Err(LumentixError::DisputeAlreadyOpen)
+        );
+
+        let cancelled = self::event(&env, EventStatus::Cancelled);
+        assert_eq!(
+            validate_dispute_opening(&cancelled, &ticket, &holder, &reason, None, 2500, 1000),
+            Err(LumentixError::InvalidStatusTransition)
+        );
+
+        let empty = String::from_str(&env, "");
+        assert_eq!(
+            validate_dispute_opening(&event, &ticket, &holder, &empty, None, 2500, 1000),
+            Err(LumentixError::EmptyString)
+        );
+        let long = String::from_bytes(&env, &[b'x'; MAX_DISPUTE_REASON_LEN as usize + 1]);
+        assert_eq!(
+            validate_dispute_opening(&event, &ticket, &holder, &long, None, 2500, 1000),
+            Err(LumentixError::StringTooLong)
+        );
+    }
+
+    #[test]
+    fn test_validate_dispute_resolution() {
+        let env = Env::default();
+        let open = dispute(&env, DisputeStatus::Open);
+        let resolved = dispute(
+            &env,
+            DisputeStatus::Resolved(DisputeResolution::FullRelease),
+        );
+
+        assert!(validate_dispute_resolution(Some(&open), &DisputeResolution::FullRefund).is_ok());
+        assert_eq!(
+            validate_dispute_resolution(Some(&open), &DisputeResolution::PartialRefund(10001)),
+            Err(LumentixError::InvalidBasisPoints)
+        );
+        assert_eq!(
+            validate_dispute_resolution(Some(&resolved), &DisputeResolution::FullRefund),
+            Err(LumentixError::DisputeNotFound)
+        );
+        assert_eq!(
+            validate_dispute_resolution(None, &DisputeResolution::FullRefund),
+            Err(LumentixError::DisputeNotFound)
+        );
+    }
+
+    #[test]
+    fn test_validate_escrow_release() {
+        let env = Env::default();
+        let event = event(&env, EventStatus::Completed);
+
+        assert_eq!(
+            validate_escrow_release(&event, None, 3000, 1000),
+            Err(LumentixError::EscrowFrozen)
+        );
+        assert!(validate_escrow_release(&event, None, 3001, 1000).is_ok());
+
+        let open = dispute(&env, DisputeStatus::Open);
+        assert_eq!(
+            validate_escrow_release(&event, Some(&open), 9999, 1000),
+            Err(LumentixError::EscrowFrozen)
+        );
+        let refunded = dispute(&env, DisputeStatus::Resolved(DisputeResolution::FullRefund));
+        assert_eq!(
+            validate_escrow_release(&event, Some(&refunded), 9999, 1000),
+            Err(LumentixError::EscrowFrozen)
+        );
+        let partial = dispute(
+            &env,
+            DisputeStatus::Resolved(DisputeResolution::PartialRefund(5000)),
+        );
+        assert!(validate_escrow_release(&event, Some(&partial), 2500, 1000).is_ok());
+    }
+
+    #[test]
+    fn test_dispute_payouts() {
+        use soroban_sdk::testutils::Address as _;
+
+        let env = Env::default();
+        let ticket = ticket(&env, &Address::generate(&env));
+        let partial = DisputeResolution::PartialRefund(5000);
+
+        assert_eq!(
+            calculate_dispute_release(1000, 0, &DisputeResolution::FullRelease),
+            Ok(1000)
+        );
+        assert_eq!(calculate_dispute_release(1001, 0, &partial), Ok(501));
+        assert_eq!(
+            calculate_dispute_release(1000, 0, &DisputeResolution::FullRefund),
+            Ok(0)
+        );
+
+        // 95 escrowed for the ticket after the 5 platform fee
+        assert_eq!(
+            calculate_dispute_refund(&ticket, &DisputeResolution::FullRelease),
+            0
+        );
+        assert_eq!(calculate_dispute_refund(&ticket, &partial), 47);
+        assert_eq!(
+            calculate_dispute_refund(&ticket, &DisputeResolution::FullRefund),
+            95
+        );
+    }
+
+    #[test]
+    fn test_dispute_release_counts_advances() {
+        let full_refund = DisputeResolution::FullRefund;
+        let partial = DisputeResolution::PartialRefund(5000);
+
+        // 1000 escrowed, 400 of it advanced: a half refund owes 500, which the
+        // remaining 600 covers, leaving 100 (500 in total) for the organizer
+        assert_eq!(calculate_dispute_shortfall(600, 400, &partial), 0);
+        assert_eq!(calculate_dispute_release(600, 400, &partial), Ok(100));
+
+        // A full refund owes 1000: the 400 advanced must be repaid first
+        assert_eq!(calculate_dispute_shortfall(600, 400, &full_refund), 400);
+        assert_eq!(
+            calculate_dispute_release(600, 400, &full_refund),
+            Err(LumentixError::RefundUnderfunded)
+        );
+        assert_eq!(calculate_dispute_shortfall(1000, 0, &full_refund), 0);
+        assert_eq!(calculate_dispute_release(1000, 0, &full_refund), Ok(0));
+
+        // A 70% refund owes 700: 100 of the advances must come back
+        let mostly_refunded = DisputeResolution::PartialRefund(7000);
+        assert_eq!(calculate_dispute_shortfall(600, 400, &mostly_refunded), 100);
+        assert_eq!(calculate_dispute_release(700, 300, &mostly_refunded), Ok(0));
+        assert_eq!(
+            calculate_dispute_release(600, 400, &DisputeResolution::FullRelease),
+            Ok(600)
+        );
+    }
+
+    fn refund_schedule(env: &Env) -> Vec<RefundRule> {
+        let mut schedule = Vec::new(env);
+        // 100% until 14 days before start, 50% until 2 days before
+        schedule.push_back(RefundRule {
+            cutoff_secs: 14 * 86400,
+            refund_bps: 10000,
+        });
+        schedule.push_back(RefundRule {
+            cutoff_secs: 2 * 86400,
+            refund_bps: 5000,
+        });
+        schedule
+    }
+
+    #[test]
+    fn test_validate_refund_schedule() {
+        let env = Env::default();
+        let schedule = refund_schedule(&env);
+        assert!(validate_refund_schedule(&schedule).is_ok());
+        assert!(validate_refund_schedule(&Vec::new(&env)).is_ok());
+
+        let mut increasing = schedule.clone();
+        increasing.push_back(RefundRule {
+            cutoff_secs: 86400,
+            refund_bps: 7500,
+        });
+        assert_eq!(
+            validate_refund_schedule(&increasing),
+            Err(LumentixError::InvalidRefundSchedule)
+        );
+
+        let mut unordered = schedule;
+        unordered.push_back(RefundRule {
+            cutoff_secs: 3 * 86400,
+            refund_bps: 0,
+        });
+        assert_eq!(
+            validate_refund_schedule(&unordered),
+            Err(LumentixError::InvalidRefundSchedule)
+        );
+    }
+
+    #[test]
+    fn test_validate_refund_schedule_editable() {
+        let env = Env::default();
+        assert!(validate_refund_schedule_editable(&event(&env, EventStatus::Draft)).is_ok());
+
+        // Holders bought under the published schedule, so it locks for good
+        for status in [
+            EventStatus::Published,
+            EventStatus::Postponed,
+            EventStatus::Completed,
+            EventStatus::Cancelled,
+        ] {
+            assert_eq!(
+                validate_refund_schedule_editable(&event(&env, status)),
+                Err(LumentixError::InvalidStatusTransition)
+            );
+        }
+    }
+
+    #[test]
+    fn test_calculate_refund_bps() {
+        let env = Env::default();
+        let schedule = refund_schedule(&env);
+        let start = 30 * 86400;
+
+        assert_eq!(calculate_refund_bps(&schedule, start, 0), 10000);
+        assert_eq!(calculate_refund_bps(&schedule, start, 16 * 86400), 10000);
+        assert_eq!(calculate_refund_bps(&schedule, start, 16 * 86400 + 1), 5000);
+        assert_eq!(calculate_refund_bps(&schedule, start, 28 * 86400), 5000);
+        assert_eq!(calculate_refund_bps(&schedule, start, 28 * 86400 + 1), 0);
+        assert_eq!(calculate_refund_bps(&Vec::new(&env), start, 0), 0);
+    }
+
+    #[test]
+    fn test_calculate_scheduled_refund() {
+        use soroban_sdk::testutils::Address as _;
+
+        let env = Env::default();
+        let schedule = refund_schedule(&env);
+        let start = 30 * 86400;
+        let ticket = ticket(&env, &Address::generate(&env));
+
+        // 100 paid with a 5 platform fee: only the escrowed 95 is refundable
+        assert_eq!(calculate_scheduled_refund(&ticket, &schedule, start, 0), 95);
+        assert_eq!(
+            calculate_scheduled_refund(&ticket, &schedule, start, 20 * 86400),
+            47
+        );
+        assert_eq!(
+            calculate_scheduled_refund(&ticket, &schedule, start, 29 * 86400),
+            0
+        );
+
+        // Full refunds of every ticket never exceed the escrow they came from
+        let mut escrow: i128 = 0;
+        for _ in 0..3 {
+            escrow += ticket.price_paid - ticket.platform_fee;
+        }
+        for _ in 0..3 {
+            escrow -= calculate_scheduled_refund(&ticket, &schedule, start, 0);
+        }
+        assert_eq!(escrow, 0);
+    }
+
+    #[test]
+    fn test_validate_waitlist_offer() {
+        use soroban_sdk::testutils::Address as _;
+
+        let env = Env::default();
+        let buyer = Address::generate(&env);
+        let other = Address::generate(&env);
+        let offer = WaitlistOffer {
+            event_id: 1,
+            buyer: buyer.clone(),
+            price: 100,
+            expires_at: 1000 + WAITLIST_OFFER_WINDOW,
+        };
+
+        assert!(validate_waitlist_offer(&offer, &buyer, 1000).is_ok());
+        assert!(validate_waitlist_offer(&offer, &buyer, offer.expires_at).is_ok());
+        assert_eq!(
+            validate_waitlist_offer(&offer, &buyer, offer.expires_at + 1),
+            Err(LumentixError::NoActiveOffer)
+        );
+        assert_eq!(
+            validate_waitlist_offer(&offer, &other, 1000),
+            Err(LumentixError::NoActiveOffer)
+        );
+    }
+
+    #[test]
+    fn test_promo_code() {
+        let env = Env::default();
+        let code = Bytes::from_slice(&env, b"SUMMER25");
+        let promo = PromoCode {
+            code_hash: hash_promo_code(&env, &code),
+            discount: Discount::Percentage(2500),
+            max_redemptions: 2,
+            redemptions: 1,
+            expires_at: 1000,
+        };
+
+        assert_eq!(
+            promo.code_hash,
+            hash_promo_code(&env, &Bytes::from_slice(&env, b"SUMMER25"))
+        );
+        assert_ne!(
+            promo.code_hash,
+            hash_promo_code(&env, &Bytes::from_slice(&env, b"WINTER25"))
+        );
+
+        assert!(validate_promo_code(&promo, 1000).is_ok());
+        assert_eq!(
+            validate_promo_code(&promo, 1001),
+            Err(LumentixError::PromoCodeUnavailable)
+        );
+
+        let exhausted = PromoCode {
+            redemptions: 2,
+            ..promo
+        };
+        assert_eq!(
+            validate_promo_code(&exhausted, 0),
+            Err(LumentixError::PromoCodeUnavailable)
+        );
+    }
+
+    #[test]
+    fn test_apply_discount() {
+        assert_eq!(apply_discount(100, &Discount::Percentage(2500)), 75);
+        assert_eq!(apply_discount(100, &Discount::Fixed(30)), 70);
+        assert_eq!(apply_discount(100, &Discount::Fixed(150)), 0);
+
+        assert!(validate_discount(&Discount::Percentage(10000)).is_ok());
+        assert_eq!(
+            validate_discount(&Discount::Percentage(10001)),
+            Err(LumentixError::InvalidBasisPoints)
+        );
+        assert_eq!(
+            validate_discount(&Discount::Fixed(0)),
+            Err(LumentixError::InvalidAmount)
+        );
+    }
+
+    fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+        let (left, right) = if a <= b { (a, b) } else { (b, a) };
+        let mut data = Bytes::new(env);
+        data.extend_from_array(&left.to_array());
+        data.extend_from_array(&right.to_array());
+        env.crypto().sha256(&data).into()
+    }
+
+    #[test]
+    fn test_validate_presale_purchase() {
+        use soroban_sdk::testutils::Address as _;
+
+        let env = Env::default();
+        let fan1 = Address::generate(&env);
+        let fan2 = Address::generate(&env);
+        let fan3 = Address::generate(&env);
+        let outsider = Address::generate(&env);
+
+        // Tree over three leaves: root = H(H(l1, l2), l3)
+        let l1 = presale_leaf(&env, &fan1);
+        let l2 = presale_leaf(&env, &fan2);
+        let l3 = presale_leaf(&env, &fan3);
+        let node = hash_pair(&env, &l1, &l2);
+        let presale = PresaleConfig {
+            merkle_root: hash_pair(&env, &node, &l3),
+            per_address_limit: 2,
+            public_sale_time: 5000,
+        };
+
+        let mut proof1 = Vec::new(&env);
+        proof1.push_back(l2.clone());
+        proof1.push_back(l3.clone());
+        assert!(validate_presale_purchase(&env, &presale, &fan1, &proof1, 0, 2).is_ok());
+        assert_eq!(
+            validate_presale_purchase(&env, &presale, &fan1, &proof1, 1, 2),
+            Err(LumentixError::PresaleLimitExceeded)
+        );
+
+        let mut proof3 = Vec::new(&env);
+        proof3.push_back(node);
+        assert!(validate_presale_purchase(&env, &presale, &fan3, &proof3, 0, 1).is_ok());
+
+        assert_eq!(
+            validate_presale_purchase(&env, &presale, &outsider, &proof1, 0, 1),
+            Err(LumentixError::NotAllowlisted)
+        );
+        assert_eq!(
+            validate_presale_purchase(&env, &presale, &fan2, &proof1, 0, 1),
+            Err(LumentixError::NotAllowlisted)
+        );
+
+        let presale = Some(presale);
+        assert!(is_presale_active(&presale, 4999));
+        assert!(!is_presale_active(&presale, 5000));
+        assert!(!is_presale_active(&None, 0));
+    }
+
+    fn auction() -> DutchAuction {
+        DutchAuction {
+            start_price: 1000,
+            floor_price: 250,
+            start_time: 100,
+            decay_period: 60,
+            price_step: 100,
+            uniform_clearing: true,
+        }
+    }
+
+    #[test]
+    fn test_calculate_auction_price() {
+        let auction = auction();
+        assert!(validate_auction(&auction).is_ok());
+
+        assert_eq!(calculate_auction_price(&auction, 0), 1000);
+        assert_eq!(calculate_auction_price(&auction, 159), 1000);
+        assert_eq!(calculate_auction_price(&auction, 160), 900);
+        assert_eq!(calculate_auction_price(&auction, 100 + 7 * 60), 300);
+        assert_eq!(calculate_auction_price(&auction, 100 + 8 * 60), 250);
+        assert_eq!(calculate_auction_price(&auction, u64::MAX), 250);
+        assert_eq!(auction_end_time(&auction), 100 + 8 * 60);
+
+        // Step counts beyond u64 and end times past u64::MAX saturate
+        let endless = DutchAuction {
+            start_price: i128::MAX,
+            floor_price: 1,
+            price_step: 1,
+            decay_period: u64::MAX,
+            ..auction.clone()
+        };
+        assert!(validate_auction(&endless).is_ok());
+        assert_eq!(auction_end_time(&endless), u64::MAX);
+        assert_eq!(calculate_auction_price(&endless, u64::MAX), i128::MAX);
+
+        let inverted = DutchAuction {
+            floor_price: 1000,
+            ..auction
+        };
+        assert_eq!(
+            validate_auction(&inverted),
+            Err(LumentixError::InvalidAuction)
+        );
+    }
+
+    #[test]
+    fn test_calculate_clearing_rebate() {
+        use soroban_sdk::testutils::Address as _;
+
+        let env = Env::default();
+        let auction = auction();
+        let mut event = event(&env, EventStatus::Published);
+        // Bought at 1000 with a 10% fee: 900 escrowed
+        let mut early = ticket(&env, &Address::generate(&env));
+        early.price_paid = 1000;
+        early.platform_fee = 100;
+
+        assert_eq!(
+            calculate_clearing_rebate(&event, &auction, &early, 400, 579),
+            Err(LumentixError::AuctionNotEnded)
+        );
+        // Repriced at 400 the fee is 40, so escrow returns 900 - 360 and the
+        // platform reverses the other 60
+        assert_eq!(
+            calculate_clearing_rebate(&event, &auction, &early, 400, 580),
+            Ok((540, 60))
+        );
+
+        // Selling out ends the auction before the floor is reached
+        let mut general = event.tiers.get(0).unwrap();
+        general.tickets_sold = general.max_tickets;
+        event.tiers.set(0, general);
+        assert_eq!(
+            calculate_clearing_rebate(&event, &auction, &early, 400, 200),
+            Ok((540, 60))
+        );
+
+        // The reversed fee rounds in the holder's favour but never exceeds the fee
+        let mut odd = early.clone();
+        odd.price_paid = 999;
+        odd.platform_fee = 7;
+        assert_eq!(
+            calculate_clearing_rebate(&event, &auction, &odd, 250, 580),
+            Ok((743, 6))
+        );
+
+        // Buyers at or below the clearing price get nothing back
+        assert_eq!(
+            calculate_clearing_rebate(&event, &auction, &early, 1000, 580),
+            Ok((0, 0))
+        );
+        let fixed = DutchAuction {
+            uniform_clearing: false,
+            ..auction.clone()
+        };
+        assert_eq!(
+            calculate_clearing_rebate(&event, &fixed, &early, 400, 580),
+            Ok((0, 0))
+        );
+
+        early.refunded = true;
+        assert_eq!(
+            calculate_clearing_rebate(&event, &auction, &early, 400, 580),
+            Err(LumentixError::RefundNotAllowed)
+        );
+    }
+
+    #[test]
+    fn test_quote_price_linear_steps() {
+        // +10 every 5 tickets
+        let curve = Some(PricingCurve::LinearSteps(5, 10));
+        assert!(validate_pricing_curve(curve.as_ref().unwrap()).is_ok());
+
+        assert_eq!(quote_price(100, &curve, 0, 50, 1), Ok(100));
+        assert_eq!(quote_price(100, &curve, 4, 50, 2), Ok(100 + 110));
+        assert_eq!(quote_price(100, &curve, 10, 50, 1), Ok(120));
+        assert_eq!(quote_price(100, &None, 10, 50, 3), Ok(300));
+        assert_eq!(
+            quote_price(100, &curve, 49, 50, 2),
+            Err(LumentixError::EventSoldOut)
+        );
+        assert_eq!(
+            validate_pricing_curve(&PricingCurve::LinearSteps(0, 10)),
+            Err(LumentixError::InvalidPricingCurve)
+        );
+
+        // A single ticket price that overflows is rejected, not just the running sum
+        let steep = Some(PricingCurve::LinearSteps(1, i128::MAX / 2));
+        assert_eq!(
+            quote_price(100, &steep, 3, 50, 1),
+            Err(LumentixError::InvalidAmount)
+        );
+    }
+
+    #[test]
+    fn test_quote_price_thresholds() {
+        use crate::types::PriceThreshold;
+
+        let env = Env::default();
+        let mut thresholds = Vec::new(&env);
+        // +10% from half full, +25% from 90% full
+        thresholds.push_back(PriceThreshold {
+            fill_bps: 5000,
+            bump_bps: 1000,
+        });
+        thresholds.push_back(PriceThreshold {
+            fill_bps: 9000,
+            bump_bps: 2500,
+        });
+        let curve = Some(PricingCurve::Thresholds(thresholds.clone()));
+        assert!(validate_pricing_curve(curve.as_ref().unwrap()).is_ok());
+
+        assert_eq!(quote_price(100, &curve, 0, 10, 1), Ok(100));
+        assert_eq!(quote_price(100, &curve, 4, 10, 2), Ok(100 + 110));
+        assert_eq!(quote_price(100, &curve, 8, 10, 2), Ok(110 + 125));
+
+        thresholds.push_back(PriceThreshold {
+            fill_bps: 8000,
+            bump_bps: 3000,
+        });
+        assert_eq!(
+            validate_pricing_curve(&PricingCurve::Thresholds(thresholds)),
+            Err(LumentixError::InvalidPricingCurve)
+        );
+    }
+
+    #[test]
+    fn test_validate_accepted_tokens() {
+        use soroban_sdk::testutils::Address as _;
+
+        let env = Env::default();
+        let usdc = Address::generate(&env);
+        let eurc = Address::generate(&env);
+
+        let mut tiers = Vec::new(&env);
+        tiers.push_back(tier(&env, 1, "General Admission", 100, 500));
+        tiers.push_back(tier(&env, 2, "VIP", 250, 50));
+
+        let mut tokens = Vec::new(&env);
+        tokens.push_back(AcceptedToken {
+            token: usdc.clone(),
+            tier_id: 1,
+            price: 100,
+        });
+        tokens.push_back(AcceptedToken {
+            token: eurc,
+            tier_id: 1,
+            price: 92,
+        });
+        // The same token may carry a different price for another tier
+        tokens.push_back(AcceptedToken {
+            token: usdc.clone(),
+            tier_id: 2,
+            price: 250,
+        });
+        assert!(validate_accepted_tokens(&tokens, &tiers).is_ok());
+
+        let mut unknown_tier = tokens.clone();
+        unknown_tier.push_back(AcceptedToken {
+            token: usdc.clone(),
+            tier_id: 3,
+            price: 50,
+        });
+        assert_eq!(
+            validate_accepted_tokens(&unknown_tier, &tiers),
+            Err(LumentixError::TierNotFound)
+        );
+
+        tokens.push_back(AcceptedToken {
+            token: usdc,
+            tier_id: 1,
+            price: 100,
+        });
+        assert_eq!(
+            validate_accepted_tokens(&tokens, &tiers),
+            Err(LumentixError::DuplicateToken)
+        );
+    }
+
+    #[test]
+    fn test_find_accepted_token() {
+        use soroban_sdk::testutils::Address as _;
+
+        let env = Env::default();
+        let default_token = Address::generate(&env);
+        let usdc = Address::generate(&env);
+        let mut event = event(&env, EventStatus::Published);
+        event.tiers.push_back(tier(&env, 2, "VIP", 250, 50));
+
+        // No accepted tokens: the default token at the tier price
+        assert_eq!(
+            find_accepted_token(&event, 1, &default_token, &default_token).map(|a| a.price),
+            Ok(100)
+        );
+        assert_eq!(
+            find_accepted_token(&event, 1, &usdc, &default_token),
+            Err(LumentixError::TokenNotAccepted)
+        );
+        assert_eq!(
+            find_accepted_token(&event, 2, &default_token, &default_token).map(|a| a.price),
+            Ok(250)
+        );
+        assert_eq!(
+            find_accepted_token(&event, 3, &default_token, &default_token),
+            Err(LumentixError::TierNotFound)
+        );
+
+        // Accepted tokens are priced per tier
+        for (tier_id, price) in [(1u32, 99i128), (2, 249)] {
+            event.accepted_tokens.push_back(AcceptedToken {
+                token: usdc.clone(),
+                tier_id,
+                price,
+            });
+        }
+        assert_eq!(
+            find_accepted_token(&event, 1, &usdc, &default_token).map(|a| a.price),
+            Ok(99)
+        );
+        assert_eq!(
+            find_accepted_token(&event, 2, &usdc, &default_token).map(|a| a.price),
+            Ok(249)
+        );
+        assert_eq!(
+            find_accepted_token(&event, 2, &default_token, &default_token),
+            Err(LumentixError::TokenNotAccepted)
+        );
+    }
+
+    fn event(env: &Env, status: EventStatus) -> Event {
+        use soroban_sdk::testutils::Address as _;
+
+        let mut general = tier(env, 1, "General Admission", 100, 50);
+        general.tickets_sold = 10;
+        Event {
+            id: 1,
+            organizer: Address::generate(env),
+            name: String::from_str(env, "Test Event"),
+            description: String::from_str(env, "Description"),
+            location: String::from_str(env, "Location"),
+            start_time: 1000,
+            end_time: 2000,
+            tiers: Vec::from_array(env, [general]),
+            refund_schedule: Vec::new(env),
+            accepted_tokens: Vec::new(env),
+            reference_currency: None,
+            status,
+        }
+    }
+
+    fn no_changes() -> EventUpdate {
+        EventUpdate {
+            name: None,
+            description: None,
+            location: None,
+            start_time: None,
+            end_time: None,
+            ticket_price: None,
+            max_tickets: None,
+            tier_id: None,
+        }
+    }
+
+    #[test]
+    fn test_apply_event_update_draft() {
+        let env = Env::default();
+        let mut draft = event(&env, EventStatus::Draft);
+
+        let update = EventUpdate {
+            name: Some(String::from_str(&env, "Renamed")),
+            start_time: Some(1500),
+            end_time: Some(2500),
+            ticket_price: Some(150),
+            max_tickets: Some(20),
+            tier_id: Some(1),
+            ..no_changes()
+        };
+        assert!(apply_event_update(&mut draft, &update).is_ok());
+        assert_eq!(draft.name, String::from_str(&env, "Renamed"));
+        assert_eq!((draft.start_time, draft.end_time), (1500, 2500));
+        let (_, general) = find_tier(&draft, 1).unwrap();
+        assert_eq!((general.price, general.max_tickets), (150, 20));
+
+        // A rejected update leaves every field untouched
+        let before = draft.clone();
+        let bad_times = EventUpdate {
+            name: Some(String::from_str(&env, "Renamed again")),
+            start_time: Some(3000),
+            ..no_changes()
+        };
+        assert_eq!(
+            apply_event_update(&mut draft, &bad_times),
+            Err(LumentixError::InvalidTimeRange)
+        );
+        let bad_capacity = EventUpdate {
+            location: Some(String::from_str(&env, "Elsewhere")),
+            max_tickets: Some(5),
+            tier_id: Some(1),
+            ..no_changes()
+        };
+        assert_eq!(
+            apply_event_update(&mut draft, &bad_capacity),
+            Err(LumentixError::CapacityExceeded)
+        );
+        assert_eq!(draft, before);
+    }
+
+    #[test]
+    fn test_apply_event_update_tiers() {
+        let env = Env::default();
+        let mut draft = event(&env, EventStatus::Draft);
+        draft.tiers.push_back(tier(&env, 2, "VIP", 250, 10));
+        assert_eq!(
+            (event_capacity(&draft), event_tickets_sold(&draft)),
+            (60, 10)
+        );
+
+        // Price and capacity changes must name a tier
+        let untargeted = EventUpdate {
+            ticket_price: Some(120),
+            ..no_changes()
+        };
+        assert_eq!(
+            apply_event_update(&mut draft, &untargeted),
+            Err(LumentixError::TierNotFound)
+        );
+
+        let vip = EventUpdate {
+            ticket_price: Some(300),
+            max_tickets: Some(20),
+            tier_id: Some(2),
+            ..no_changes()
+        };
+        assert!(apply_event_update(&mut draft, &vip).is_ok());
+        let (_, updated) = find_tier(&draft, 2).unwrap();
+        assert_eq!((updated.price, updated.max_tickets), (300, 20));
+        assert_eq!(find_tier(&draft, 1).unwrap().1.price, 100);
+        assert_eq!(event_capacity(&draft), 70);
+
+        // Published: a tier can grow but not shrink
+        draft.status = EventStatus::Published;
+        let shrink = EventUpdate {
+            max_tickets: Some(30),
+            tier_id: Some(1),
+            ..no_changes()
+        };
+        assert_eq!(
+            apply_event_update(&mut draft, &shrink),
+            Err(LumentixError::UpdateNotAllowed)
+        );
+        let grow = EventUpdate {
+            max_tickets: Some(60),
+            tier_id: Some(1),
+            ..no_changes()
+        };
+        assert!(apply_event_update(&mut draft, &grow).is_ok());
+        assert_eq!(find_tier(&draft, 1).unwrap().1.max_tickets, 60);
+        assert_eq!(event_capacity(&draft), 80);
+    }
+
+    #[test]
+    fn test_apply_event_update_published() {
+        let env = Env::default();
+        let mut published = event(&env, EventStatus::Published);
+
+        let update = EventUpdate {
+            location: Some(String::from_str(&env, "New Venue")),
+            max_tickets: Some(80),
+            tier_id: Some(1),
+            ..no_changes()
+        };
+        assert!(apply_event_update(&mut published, &update).is_ok());
+        assert_eq!(published.location, String::from_str(&env, "New Venue"));
+        assert_eq!(event_capacity(&published), 80);
+
+        let price = EventUpdate {
+            ticket_price: Some(90),
+            tier_id: Some(1),
+            ..no_changes()
+        };
+        assert_eq!(
+            apply_event_update(&mut published, &price),
+            Err(LumentixError::UpdateNotAllowed)
+        );
+
+        let shrink = EventUpdate {
+            max_tickets: Some(60),
+            tier_id: Some(1),
+            ..no_changes()
+        };
+        assert_eq!(
+            apply_event_update(&mut published, &shrink),
+            Err(LumentixError::UpdateNotAllowed)
+        );
+
+        for status in [EventStatus::Completed, EventStatus::Cancelled] {
+            let mut closed = event(&env, status);
+            assert_eq!(
+                apply_event_update(&mut closed, &no_changes()),
+                Err(LumentixError::UpdateNotAllowed)
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_status_transition() {
+        use EventStatus::*;
+
+        assert!(validate_status_transition(&Draft, &Published).is_ok());
+        assert!(validate_status_transition(&Published, &Postponed).is_ok());
+        assert!(validate_status_transition(&Postponed, &Published).is_ok());
+        assert!(validate_status_transition(&Postponed, &Cancelled).is_ok());
+
+        for (from, to) in [
+            (Draft, Postponed),
+            (Postponed, Completed),
+            (Postponed, Postponed),
+            (Completed, Postponed),
+            (Cancelled, Published),
+        ] {
+            assert_eq!(
+                validate_status_transition(&from, &to),
+                Err(LumentixError::InvalidStatusTransition)
+            );
+        }
+    }
+
+    #[test]
+    fn test_postpone_event_and_opt_out() {
+        let env = Env::default();
+        let mut published = event(&env, EventStatus::Published);
+
+        let postponement = postpone_event(&mut published, 5000, 6000, 1000, 500).unwrap();
+        assert_eq!(published.status, EventStatus::Postponed);
+        assert_eq!((published.start_time, published.end_time), (5000, 6000));
+        assert_eq!(postponement.original_start_time, 1000);
+        assert_eq!(postponement.opt_out_deadline, 1500);
+
+        assert!(validate_opt_out(&published, &postponement, 1500).is_ok());
+        assert_eq!(
+            validate_opt_out(&published, &postponement, 1501),
+            Err(LumentixError::OptOutWindowClosed)
+        );
+        assert_eq!(
+            validate_postponement_ended(&postponement, 1500),
+            Err(LumentixError::OptOutWindowOpen)
+        );
+        assert!(validate_postponement_ended(&postponement, 1501).is_ok());
+
+        let mut draft = event(&env, EventStatus::Draft);
+        assert_eq!(
+            postpone_event(&mut draft, 5000, 6000, 1000, 500),
+            Err(LumentixError::InvalidStatusTransition)
+        );
+
+        let mut too_soon = event(&env, EventStatus::Published);
+        assert_eq!(
+            postpone_event(&mut too_soon, 1200, 6000, 1000, 500),
+            Err(LumentixError::InvalidTimeRange)
+        );
+    }
+
+    #[test]
+    fn test_validate_ttl_config() {
+        let config = |threshold, extend_to| TtlConfig {
+            threshold,
+            extend_to,
+        };
+
+        assert!(validate_ttl_config(&config(100, 1000), 1000).is_ok());
+        assert_eq!(
+            validate_ttl_config(&config(0, 1000), 1000),
+            Err(LumentixError::InvalidTtlConfig)
+        );
+        assert_eq!(
+            validate_ttl_config(&config(1000, 1000), 2000),
+            Err(LumentixError::InvalidTtlConfig)
+        );
+        assert_eq!(
+            validate_ttl_config(&config(100, 1001), 1000),
+            Err(LumentixError::InvalidTtlConfig)
+        );
+    }
+
+    #[test]
+    fn test_validate_admin_set() {
+        use soroban_sdk::testutils::Address as _;
+
+        let env = Env::default();
+        let a = Address::generate(&env);
+        let b = Address::generate(&env);
+        let c = Address::generate(&env);
+        let admins = Vec::from_array(&env, [a.clone(), b.clone(), c]);
+
+        assert!(validate_admin_set(&admins, 2).is_ok());
+        assert!(validate_admin_set(&admins, 3).is_ok());
+        assert_eq!(
+            validate_admin_set(&admins, 0),
+            Err(LumentixError::InvalidThreshold)
+        );
+        assert_eq!(
+            validate_admin_set(&admins, 4),
+            Err(LumentixError::InvalidThreshold)
+        );
+        assert_eq!(
+            validate_admin_set(&Vec::from_array(&env, [a, b.clone(), b]), 2),
+            Err(LumentixError::InvalidThreshold)
+        );
+
+        assert!(validate_approvals(2, 2).is_ok());
+        assert_eq!(
+            validate_approvals(1, 2),
+            Err(LumentixError::ThresholdNotMet)
+        );
+    }
+
+    #[test]
+    fn test_validate_tiers() {
+        let env = Env::default();
+
+        let mut tiers = Vec::new(&env);
+        assert_eq!(validate_tiers(&tiers), Err(LumentixError::CapacityExceeded));
+
+        tiers.push_back(tier(&env, 1, "General Admission", 100, 500));
+        tiers.push_back(tier(&env, 2, "VIP", 250, 50));
+        assert!(validate_tiers(&tiers).is_ok());
+
+        let mut duplicate = tiers.clone();
+        duplicate.push_back(tier(&env, 2, "Student", 50, 100));
+        assert_eq!(
+            validate_tiers(&duplicate),
+            Err(LumentixError::DuplicateTier)
+        );
+
+        let mut free = tiers.clone();
+        free.push_back(tier(&env, 3, "Student", 0, 100));
+        assert_eq!(validate_tiers(&free), Err(LumentixError::InvalidAmount));
+
+        let mut unnamed = tiers;
+        unnamed.push_back(tier(&env, 3, "", 50, 100));
+        assert_eq!(validate_tiers(&unnamed), Err(LumentixError::EmptyString));
+    }
+
+    #[test]
+    fn test_validate_tier_capacity() {
+        let env = Env::default();
+        let mut vip = tier(&env, 2, "VIP", 250, 2);
+
+        assert!(validate_tier_capacity(&vip, 2).is_ok());
+        vip.tickets_sold = 2;
+        assert_eq!(
+            validate_tier_capacity(&vip, 1),
+            Err(LumentixError::EventSoldOut)
+        );
+    }
 }